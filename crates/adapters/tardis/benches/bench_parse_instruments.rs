@@ -0,0 +1,117 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fs,
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use nautilus_tardis::http::models::InstrumentInfo;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+/// An allocator tracking the peak heap size, to compare the peak memory of parsing a
+/// buffered body with parsing the body as it is read.
+struct PeakAlloc;
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK_ALLOCATED.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: PeakAlloc = PeakAlloc;
+
+/// Returns the peak heap size allocated while calling `f`, in bytes.
+fn peak_allocated<T>(f: impl FnOnce() -> T) -> usize {
+    let base = ALLOCATED.load(Ordering::Relaxed);
+    PEAK_ALLOCATED.store(base, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK_ALLOCATED.load(Ordering::Relaxed) - base;
+    drop(result);
+    peak
+}
+
+/// Returns an instruments response body of several MB, repeating a fixture definition.
+fn instruments_body() -> Vec<u8> {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("tests")
+        .join("data")
+        .join("instrument_perpetual_changes.json");
+    let info = fs::read_to_string(path).expect("Failed to read fixture");
+    format!("[{}]", vec![info; 5_000].join(",")).into_bytes()
+}
+
+/// Parses the `body` after buffering a copy of it, as when the full response is read first.
+fn parse_buffered(body: &[u8]) -> Vec<InstrumentInfo> {
+    let body = body.to_vec();
+    serde_json::from_slice(&body).expect("Failed to parse")
+}
+
+/// Parses the `body` as it is read, as when the response is deserialized while received.
+fn parse_streamed(body: &[u8]) -> Vec<InstrumentInfo> {
+    serde_json::from_reader(std::io::BufReader::new(body)).expect("Failed to parse")
+}
+
+fn bench_parse_instruments(c: &mut Criterion) {
+    let body = instruments_body();
+
+    let buffered_peak = peak_allocated(|| parse_buffered(&body));
+    let streamed_peak = peak_allocated(|| parse_streamed(&body));
+    println!(
+        "Peak heap parsing a {} byte body: buffered {buffered_peak} bytes, streamed {streamed_peak} bytes",
+        body.len(),
+    );
+
+    let mut group = c.benchmark_group("parse_instruments");
+    group.throughput(Throughput::Bytes(body.len() as u64));
+    group.sample_size(20);
+
+    group.bench_function("from_utf8_then_from_str", |b| {
+        b.iter(|| {
+            let text = String::from_utf8(body.clone()).expect("Invalid UTF-8");
+            let _: Vec<InstrumentInfo> = serde_json::from_str(&text).expect("Failed to parse");
+        });
+    });
+    group.bench_function("from_slice", |b| {
+        b.iter(|| {
+            let body = body.clone();
+            let _: Vec<InstrumentInfo> = serde_json::from_slice(&body).expect("Failed to parse");
+        });
+    });
+    group.bench_function("buffered", |b| b.iter(|| parse_buffered(&body)));
+    group.bench_function("streamed_from_reader", |b| b.iter(|| parse_streamed(&body)));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_instruments);
+criterion_main!(benches);
@@ -13,7 +13,10 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use nautilus_tardis::{enums::Exchange, http::client::TardisHttpClient};
+use nautilus_tardis::{
+    enums::Exchange,
+    http::{client::TardisHttpClient, query::InstrumentOptions},
+};
 
 #[tokio::main]
 async fn main() {
@@ -24,15 +27,24 @@ async fn main() {
     let client = TardisHttpClient::new(None, None, None, true).unwrap();
 
     // Tardis instrument definitions
-    let resp = client.instruments_info(Exchange::Okex, None).await;
+    let resp = client.instruments_info(Exchange::Okex, None, None).await;
     println!("Received: {resp:?}");
 
-    let resp = client.instrument_info(Exchange::Okex, "ETH-USD").await;
+    let resp = client
+        .instrument_info(Exchange::Okex, "ETH-USD", None)
+        .await;
     println!("Received: {resp:?}");
 
     // Nautilus instrument definitions
     let resp = client
-        .instruments(Exchange::Deribit, None, None, None, None)
+        .instruments(
+            Exchange::Deribit,
+            None,
+            None,
+            None,
+            None,
+            &InstrumentOptions::default(),
+        )
         .await;
     println!("Received: {resp:?}");
 
@@ -41,7 +53,14 @@ async fn main() {
     // }
 
     let resp = client
-        .instrument(Exchange::Okex, "BTC-USD", None, None, None)
+        .instrument(
+            Exchange::Okex,
+            "BTC-USD",
+            None,
+            None,
+            None,
+            &InstrumentOptions::default(),
+        )
         .await;
     println!("Received: {resp:?}");
 }
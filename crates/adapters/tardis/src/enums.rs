@@ -13,12 +13,15 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::borrow::Cow;
+
 use nautilus_model::identifiers::Venue;
 use serde::{Deserialize, Serialize};
-use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
+use strum::{Display, EnumIter, EnumString};
 use ustr::Ustr;
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, EnumIter)]
+#[strum(serialize_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 /// The instrument type for the symbol.
 pub enum InstrumentType {
@@ -29,6 +32,82 @@ pub enum InstrumentType {
     Combo,
 }
 
+/// The contract type of an instrument, for filtering the instruments metadata API.
+///
+/// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display, EnumIter, EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContractType {
+    Move,
+    LinearFuture,
+    InverseFuture,
+    QuantoFuture,
+    LinearPerpetual,
+    InversePerpetual,
+    QuantoPerpetual,
+    PutOption,
+    CallOption,
+    TurboPutOption,
+    TurboCallOption,
+    Spread,
+    InterestRateSwap,
+    Repo,
+    Index,
+}
+
+/// The Bybit product category of an instrument, which determines the Bybit API category
+/// (and account) orders for the instrument are routed to.
+///
+/// See <https://bybit-exchange.github.io/docs/v5/enum#category>.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Display, EnumIter, EnumString,
+)]
+#[strum(serialize_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum BybitProductCategory {
+    Spot,
+    Linear,
+    Inverse,
+    Option,
+}
+
+impl BybitProductCategory {
+    /// Returns the product category of a Bybit instrument with the given `instrument_type`
+    /// (and `is_inverse` flag for derivatives), or `None` for combos.
+    ///
+    /// Derivatives are linear unless flagged as inverse.
+    #[must_use]
+    pub const fn from_instrument_type(
+        instrument_type: &InstrumentType,
+        is_inverse: Option<bool>,
+    ) -> Option<Self> {
+        match instrument_type {
+            InstrumentType::Spot => Some(Self::Spot),
+            InstrumentType::Perpetual | InstrumentType::Future => match is_inverse {
+                Some(true) => Some(Self::Inverse),
+                _ => Some(Self::Linear),
+            },
+            InstrumentType::Option => Some(Self::Option),
+            InstrumentType::Combo => None,
+        }
+    }
+
+    /// Returns the suffix of the Nautilus symbols of instruments in the category
+    /// (e.g. `-LINEAR` for `BTCUSDT-LINEAR.BYBIT`).
+    #[must_use]
+    pub const fn symbol_suffix(&self) -> &'static str {
+        match self {
+            Self::Spot => "-SPOT",
+            Self::Linear => "-LINEAR",
+            Self::Inverse => "-INVERSE",
+            Self::Option => "-OPTION",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display)]
 #[serde(rename_all = "lowercase")]
 /// The type of option.
@@ -56,25 +135,15 @@ pub enum BarKind {
     Tick,
 }
 
-#[derive(
-    Debug,
-    Clone,
-    PartialEq,
-    Eq,
-    Hash,
-    Serialize,
-    Deserialize,
-    Display,
-    AsRefStr,
-    EnumIter,
-    EnumString,
-    FromRepr,
-)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display, EnumString)]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "kebab-case")]
 #[serde(rename_all = "kebab-case")]
 /// Represents a crypto exchange.
 /// See <https://api.tardis.dev/v1/exchanges> for all supported exchanges.
+///
+/// Exchanges which are not yet known to this adapter are preserved as [`Exchange::Other`],
+/// so that new Tardis venues can still be requested and round-trip without data loss.
 pub enum Exchange {
     Ascendex,
     Binance,
@@ -127,9 +196,17 @@ pub enum Exchange {
     StarAtlas,
     Upbit,
     WooX,
+    /// An exchange not yet known to this adapter, holding the original Tardis API string.
+    #[strum(default)]
+    #[serde(untagged)]
+    Other(String),
 }
 
 impl Exchange {
+    /// Returns the Tardis exchanges which share the given Nautilus venue string.
+    ///
+    /// An unknown venue maps to an [`Exchange::Other`] with its kebab case form, reversing
+    /// [`Exchange::as_venue_str`].
     #[must_use]
     pub fn from_venue_str(s: &str) -> Vec<Self> {
         let s = s.to_ascii_uppercase();
@@ -185,13 +262,17 @@ impl Exchange {
             "STARATLAS" => vec![Self::StarAtlas],
             "UPBIT" => vec![Self::Upbit],
             "WOOX" => vec![Self::WooX],
-            _ => Vec::new(),
+            "" => Vec::new(),
+            other => vec![Self::Other(other.to_lowercase().replace('_', "-"))],
         }
     }
 
+    /// Returns the Nautilus venue string for the exchange.
+    ///
+    /// For [`Exchange::Other`] the venue is the upper snake case form of the Tardis API string.
     #[must_use]
-    pub const fn as_venue_str(&self) -> &str {
-        match self {
+    pub fn as_venue_str(&self) -> Cow<'static, str> {
+        let venue = match self {
             Self::Ascendex => "ASCENDEX",
             Self::Binance => "BINANCE",
             Self::BinanceDelivery => "BINANCE_DELIVERY",
@@ -243,11 +324,162 @@ impl Exchange {
             Self::StarAtlas => "STARATLAS",
             Self::Upbit => "UPBIT",
             Self::WooX => "WOOX",
-        }
+            Self::Other(s) => return Cow::Owned(s.to_uppercase().replace('-', "_")),
+        };
+        Cow::Borrowed(venue)
     }
 
+    /// Returns the Nautilus venue for the exchange (see [`Self::as_venue_str`]).
     #[must_use]
     pub fn as_venue(&self) -> Venue {
-        Venue::from_ustr_unchecked(Ustr::from(self.as_venue_str()))
+        Venue::from_ustr_unchecked(Ustr::from(&self.as_venue_str()))
+    }
+}
+
+impl AsRef<str> for Exchange {
+    /// Returns the Tardis API string for the exchange, as displayed.
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Ascendex => "ascendex",
+            Self::Binance => "binance",
+            Self::BinanceDelivery => "binance-delivery",
+            Self::BinanceDex => "binance-dex",
+            Self::BinanceFutures => "binance-futures",
+            Self::BinanceJersey => "binance-jersey",
+            Self::BinanceOptions => "binance-options",
+            Self::BinanceUs => "binance-us",
+            Self::Bitfinex => "bitfinex",
+            Self::BitfinexDerivatives => "bitfinex-derivatives",
+            Self::Bitflyer => "bitflyer",
+            Self::Bitmex => "bitmex",
+            Self::Bitnomial => "bitnomial",
+            Self::Bitstamp => "bitstamp",
+            Self::BlockchainCom => "blockchain-com",
+            Self::Bybit => "bybit",
+            Self::BybitOptions => "bybit-options",
+            Self::BybitSpot => "bybit-spot",
+            Self::Coinbase => "coinbase",
+            Self::Coinflex => "coinflex",
+            Self::CryptoCom => "crypto-com",
+            Self::CryptoComDerivatives => "crypto-com-derivatives",
+            Self::Cryptofacilities => "cryptofacilities",
+            Self::Delta => "delta",
+            Self::Deribit => "deribit",
+            Self::Dydx => "dydx",
+            Self::Ftx => "ftx",
+            Self::FtxUs => "ftx-us",
+            Self::GateIo => "gate-io",
+            Self::GateIoFutures => "gate-io-futures",
+            Self::Gemini => "gemini",
+            Self::Hitbtc => "hitbtc",
+            Self::Huobi => "huobi",
+            Self::HuobiDm => "huobi-dm",
+            Self::HuobiDmLinearSwap => "huobi-dm-linear-swap",
+            Self::HuobiDmOptions => "huobi-dm-options",
+            Self::HuobiDmSwap => "huobi-dm-swap",
+            Self::Kraken => "kraken",
+            Self::Kucoin => "kucoin",
+            Self::Mango => "mango",
+            Self::Okcoin => "okcoin",
+            Self::Okex => "okex",
+            Self::OkexFutures => "okex-futures",
+            Self::OkexOptions => "okex-options",
+            Self::OkexSwap => "okex-swap",
+            Self::Phemex => "phemex",
+            Self::Poloniex => "poloniex",
+            Self::Serum => "serum",
+            Self::StarAtlas => "star-atlas",
+            Self::Upbit => "upbit",
+            Self::WooX => "woo-x",
+            Self::Other(s) => s,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("binance-futures", Exchange::BinanceFutures)]
+    #[case("BINANCE-FUTURES", Exchange::BinanceFutures)]
+    #[case("deribit", Exchange::Deribit)]
+    #[case("new-venue", Exchange::Other("new-venue".to_string()))]
+    fn test_exchange_from_str(#[case] value: &str, #[case] expected: Exchange) {
+        assert_eq!(Exchange::from_str(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case(Exchange::BinanceFutures, "binance-futures")]
+    #[case(Exchange::Other("new-venue".to_string()), "new-venue")]
+    fn test_exchange_display(#[case] exchange: Exchange, #[case] expected: &str) {
+        assert_eq!(exchange.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("\"okex-swap\"", Exchange::OkexSwap)]
+    #[case("\"new-venue\"", Exchange::Other("new-venue".to_string()))]
+    fn test_exchange_serde_round_trip(#[case] json: &str, #[case] expected: Exchange) {
+        let exchange: Exchange = serde_json::from_str(json).unwrap();
+        assert_eq!(exchange, expected);
+        assert_eq!(serde_json::to_string(&exchange).unwrap(), json);
+    }
+
+    #[rstest]
+    #[case(InstrumentType::Spot, "spot")]
+    #[case(InstrumentType::Perpetual, "perpetual")]
+    #[case(InstrumentType::Future, "future")]
+    #[case(InstrumentType::Option, "option")]
+    #[case(InstrumentType::Combo, "combo")]
+    fn test_instrument_type_api_token(#[case] value: InstrumentType, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            format!("\"{expected}\"")
+        );
+    }
+
+    #[rstest]
+    #[case(ContractType::Move, "move")]
+    #[case(ContractType::LinearFuture, "linear_future")]
+    #[case(ContractType::InversePerpetual, "inverse_perpetual")]
+    #[case(ContractType::QuantoPerpetual, "quanto_perpetual")]
+    #[case(ContractType::TurboCallOption, "turbo_call_option")]
+    #[case(ContractType::InterestRateSwap, "interest_rate_swap")]
+    fn test_contract_type_api_token(#[case] value: ContractType, #[case] expected: &str) {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            format!("\"{expected}\"")
+        );
+        assert_eq!(ContractType::from_str(expected).unwrap(), value);
+    }
+
+    #[rstest]
+    fn test_unknown_exchange_venue() {
+        let exchange = Exchange::Other("new-venue".to_string());
+        assert_eq!(exchange.as_venue_str(), "NEW_VENUE");
+        assert_eq!(exchange.as_venue(), Venue::from("NEW_VENUE"));
+        assert_eq!(
+            Exchange::from_venue_str(&exchange.as_venue_str()),
+            vec![exchange]
+        );
+    }
+
+    #[rstest]
+    #[case(Exchange::BinanceFutures)]
+    #[case(Exchange::GateIoFutures)]
+    #[case(Exchange::HuobiDmLinearSwap)]
+    #[case(Exchange::WooX)]
+    #[case(Exchange::Other("new-venue".to_string()))]
+    fn test_exchange_as_ref_matches_display(#[case] exchange: Exchange) {
+        assert_eq!(exchange.as_ref(), exchange.to_string());
     }
 }
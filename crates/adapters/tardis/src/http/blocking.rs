@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A synchronous wrapper around the [`TardisHttpClient`] for non-async callers.
+
+use std::time::Duration;
+
+use nautilus_model::instruments::InstrumentAny;
+
+use super::{
+    client::TardisHttpClient,
+    error::Result,
+    models::{ExchangeDetails, InstrumentInfo},
+    query::{InstrumentFilter, InstrumentOptions},
+};
+use crate::enums::Exchange;
+
+/// A blocking client for the Tardis HTTP API, driving a [`TardisHttpClient`] on an
+/// internal single-threaded runtime.
+///
+/// The methods must not be called from within an async context, as blocking on the
+/// runtime there panics.
+#[derive(Debug)]
+pub struct TardisBlockingHttpClient {
+    inner: TardisHttpClient,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl TardisBlockingHttpClient {
+    /// Creates a new [`TardisBlockingHttpClient`] instance wrapping the given `client`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime fails to build.
+    pub fn new(client: TardisHttpClient) -> anyhow::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self {
+            inner: client,
+            runtime,
+        })
+    }
+
+    /// Returns a reference to the wrapped async client.
+    #[must_use]
+    pub const fn inner(&self) -> &TardisHttpClient {
+        &self.inner
+    }
+
+    /// Returns the details of all exchanges supported by Tardis.
+    ///
+    /// See [`TardisHttpClient::exchanges`].
+    pub fn exchanges_blocking(&self, timeout: Option<Duration>) -> Result<Vec<ExchangeDetails>> {
+        self.runtime.block_on(self.inner.exchanges(timeout))
+    }
+
+    /// Returns all Tardis instrument definitions for the given `exchange`.
+    ///
+    /// See [`TardisHttpClient::instruments_info`].
+    pub fn instruments_info_blocking(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentInfo>> {
+        self.runtime
+            .block_on(self.inner.instruments_info(exchange, filter, timeout))
+    }
+
+    /// Returns the Tardis instrument definition for a given `exchange` and `symbol`.
+    ///
+    /// See [`TardisHttpClient::instrument_info`].
+    pub fn instrument_info_blocking(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+        timeout: Option<Duration>,
+    ) -> Result<InstrumentInfo> {
+        self.runtime
+            .block_on(self.inner.instrument_info(exchange, symbol, timeout))
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`.
+    ///
+    /// See [`TardisHttpClient::instruments`].
+    pub fn instruments_blocking(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        options: &InstrumentOptions,
+    ) -> Result<Vec<InstrumentAny>> {
+        self.runtime.block_on(
+            self.inner
+                .instruments(exchange, start, end, ts_init, filter, options),
+        )
+    }
+
+    /// Returns the Nautilus instrument definitions for a given `exchange` and `symbol`.
+    ///
+    /// See [`TardisHttpClient::instrument`].
+    pub fn instrument_blocking(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        options: &InstrumentOptions,
+    ) -> Result<Vec<InstrumentAny>> {
+        self.runtime.block_on(
+            self.inner
+                .instrument(exchange, symbol, start, end, ts_init, options),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use axum::{routing::get, Router};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::tests::{load_test_json, start_mock_server};
+
+    fn router() -> Router {
+        Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(|| async { format!("[{}]", load_test_json("instrument_spot.json")) }),
+            )
+            .route(
+                "/instruments/{exchange}/{symbol}",
+                get(|| async { load_test_json("instrument_spot.json") }),
+            )
+    }
+
+    #[rstest]
+    fn test_blocking_matches_async() {
+        // The mock server runs on its own runtime, as the blocking client must not be
+        // called from within an async context
+        let server_runtime = tokio::runtime::Runtime::new().unwrap();
+        let base_url = server_runtime.block_on(start_mock_server(router()));
+        let client = TardisHttpClient::new(Some("test-key"), Some(&base_url), None, true).unwrap();
+        let blocking = TardisBlockingHttpClient::new(client.clone()).unwrap();
+
+        let expected = server_runtime
+            .block_on(client.instruments(
+                Exchange::Deribit,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            ))
+            .unwrap();
+        let instruments = blocking
+            .instruments_blocking(
+                Exchange::Deribit,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .unwrap();
+        assert!(!instruments.is_empty());
+        assert_eq!(instruments.len(), expected.len());
+        assert_eq!(instruments, expected);
+
+        let expected = server_runtime
+            .block_on(client.instrument(
+                Exchange::Deribit,
+                "BTC_USDC",
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            ))
+            .unwrap();
+        let instrument = blocking
+            .instrument_blocking(
+                Exchange::Deribit,
+                "BTC_USDC",
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .unwrap();
+        assert_eq!(instrument, expected);
+    }
+}
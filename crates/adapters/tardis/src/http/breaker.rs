@@ -0,0 +1,204 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A circuit breaker which stops requests to the Tardis API while it is failing.
+//!
+//! After a threshold of consecutive failures the circuit opens, and requests fail fast
+//! until a cooldown has elapsed. A single probe request is then let through (half-open):
+//! if it succeeds the circuit closes, otherwise it stays open for another cooldown.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use super::error::Error;
+
+#[derive(Debug, Default)]
+struct CircuitState {
+    consecutive_failures: u32,
+    /// When the circuit opened, or when the last half-open probe was let through.
+    opened_at: Option<Instant>,
+}
+
+/// A thread-safe circuit breaker, shared between all clones of a client.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: Mutex<CircuitState>,
+}
+
+impl CircuitBreaker {
+    /// Creates a new [`CircuitBreaker`] instance, which opens after `threshold` (at least
+    /// one) consecutive failures and fails requests fast for `cooldown`.
+    #[must_use]
+    pub fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold: threshold.max(1),
+            cooldown,
+            state: Mutex::new(CircuitState::default()),
+        }
+    }
+
+    /// Returns the number of consecutive failures which open the circuit.
+    #[must_use]
+    pub const fn threshold(&self) -> u32 {
+        self.threshold
+    }
+
+    /// Returns how long requests fail fast once the circuit opens.
+    #[must_use]
+    pub const fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Returns whether the circuit is currently open.
+    #[must_use]
+    pub fn is_open(&self) -> bool {
+        self.state
+            .lock()
+            .expect("Circuit breaker mutex poisoned")
+            .opened_at
+            .is_some()
+    }
+
+    /// Checks whether a request may be sent.
+    ///
+    /// Once the cooldown of an open circuit has elapsed, a single probe request is
+    /// allowed per cooldown.
+    ///
+    /// # Errors
+    ///
+    /// Returns the time remaining until the next probe if the circuit is open.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().expect("Circuit breaker mutex poisoned");
+        let Some(opened_at) = state.opened_at else {
+            return Ok(());
+        };
+
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.cooldown {
+            return Err(self.cooldown - elapsed);
+        }
+
+        tracing::debug!("Circuit breaker half-open, sending probe request");
+        state.opened_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Records the outcome of a request, where only failures of the Tardis API itself
+    /// (see [`is_failure`]) count towards opening the circuit.
+    pub fn record<T>(&self, result: &Result<T, Error>) {
+        match result {
+            Err(e) if is_failure(e) => self.record_failure(),
+            _ => self.record_success(),
+        }
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().expect("Circuit breaker mutex poisoned");
+        state.consecutive_failures = 0;
+        if state.opened_at.take().is_some() {
+            tracing::info!("Circuit breaker closed");
+        }
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().expect("Circuit breaker mutex poisoned");
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.consecutive_failures >= self.threshold {
+            if state.opened_at.is_none() {
+                tracing::warn!(
+                    "Circuit breaker opened after {} consecutive failures, failing requests for {:?}",
+                    state.consecutive_failures,
+                    self.cooldown,
+                );
+            }
+            state.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+/// Returns whether the `error` indicates the Tardis API is failing (a transport error or
+/// server error), rather than a problem with the request itself.
+#[must_use]
+pub fn is_failure(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Request(_) | Error::ServerError { .. } | Error::ServiceUnavailable { .. }
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn server_error() -> Result<(), Error> {
+        Err(Error::from_status(500, 500, "Error".to_string(), None))
+    }
+
+    #[rstest]
+    fn test_opens_after_threshold() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.record(&server_error());
+        assert!(breaker.try_acquire().is_ok());
+
+        breaker.record(&server_error());
+        assert!(breaker.is_open());
+        assert!(breaker.try_acquire().unwrap_err() <= Duration::from_secs(60));
+    }
+
+    #[rstest]
+    fn test_success_resets_failures() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+
+        breaker.record(&server_error());
+        breaker.record(&Ok(()));
+        breaker.record(&server_error());
+
+        assert!(!breaker.is_open());
+    }
+
+    #[rstest]
+    fn test_client_errors_do_not_count() {
+        let breaker = CircuitBreaker::new(1, Duration::from_secs(60));
+
+        breaker.record::<()>(&Err(Error::from_status(404, 0, String::new(), None)));
+
+        assert!(!breaker.is_open());
+    }
+
+    #[rstest]
+    fn test_half_open_allows_single_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::ZERO);
+        breaker.record(&server_error());
+
+        // Probe allowed after the cooldown, and a failed probe keeps the circuit open
+        assert!(breaker.try_acquire().is_ok());
+        breaker.record(&server_error());
+        assert!(breaker.is_open());
+
+        assert!(breaker.try_acquire().is_ok());
+        breaker.record(&Ok(()));
+        assert!(!breaker.is_open());
+    }
+}
@@ -0,0 +1,450 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! In-memory and on-disk caching of Tardis HTTP API responses.
+
+use std::{
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use chrono::NaiveDate;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use reqwest::header::{
+    HeaderMap, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+};
+use sha2::{Digest, Sha256};
+
+use super::query::InstrumentFilter;
+use crate::enums::Exchange;
+
+/// A thread-safe in-memory cache where entries expire after a fixed time-to-live.
+#[derive(Debug)]
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// Creates a new [`TtlCache`] instance.
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the time-to-live for entries.
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Returns a clone of the cached value for `key`, if present and not expired.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut entries = self.entries.lock().expect("Cache mutex poisoned");
+        match entries.get(key) {
+            Some((inserted_at, value)) if inserted_at.elapsed() < self.ttl => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Inserts the `value` for `key`, replacing any existing entry.
+    pub fn insert(&self, key: K, value: V) {
+        self.entries
+            .lock()
+            .expect("Cache mutex poisoned")
+            .insert(key, (Instant::now(), value));
+    }
+
+    /// Removes the entry for `key`, if present.
+    pub fn remove(&self, key: &K) {
+        self.entries
+            .lock()
+            .expect("Cache mutex poisoned")
+            .remove(key);
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.entries.lock().expect("Cache mutex poisoned").clear();
+    }
+}
+
+/// A thread-safe in-memory cache of lookups, which also caches lookups that found nothing
+/// (such as a 404 response) with a separate time-to-live, along with their error `E`.
+#[derive(Debug)]
+pub struct LookupCache<K, V, E = String> {
+    found: TtlCache<K, V>,
+    not_found: TtlCache<K, E>,
+}
+
+impl<K, V, E> LookupCache<K, V, E>
+where
+    K: Eq + Hash,
+    V: Clone,
+    E: Clone,
+{
+    /// Creates a new [`LookupCache`] instance, where found values expire after `ttl` and
+    /// lookups which found nothing after `not_found_ttl`.
+    #[must_use]
+    pub fn new(ttl: Duration, not_found_ttl: Duration) -> Self {
+        Self {
+            found: TtlCache::new(ttl),
+            not_found: TtlCache::new(not_found_ttl),
+        }
+    }
+
+    /// Returns the time-to-live for found values.
+    #[must_use]
+    pub const fn ttl(&self) -> Duration {
+        self.found.ttl()
+    }
+
+    /// Returns the time-to-live for lookups which found nothing.
+    #[must_use]
+    pub const fn not_found_ttl(&self) -> Duration {
+        self.not_found.ttl()
+    }
+
+    /// Returns the cached result of the lookup for `key`, if present and not expired:
+    /// either the found value, or the error of the lookup which found nothing.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<Result<V, E>> {
+        self.found
+            .get(key)
+            .map(Ok)
+            .or_else(|| self.not_found.get(key).map(Err))
+    }
+
+    /// Inserts the found `value` for `key`, replacing any existing entry.
+    pub fn insert(&self, key: K, value: V) {
+        self.not_found.remove(&key);
+        self.found.insert(key, value);
+    }
+
+    /// Records that the lookup for `key` found nothing, with the `error`.
+    pub fn insert_not_found(&self, key: K, error: E) {
+        self.found.remove(&key);
+        self.not_found.insert(key, error);
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.found.clear();
+        self.not_found.clear();
+    }
+}
+
+/// A response value along with the validators needed to revalidate it.
+#[derive(Debug)]
+struct Validated<V> {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+    value: V,
+}
+
+/// A thread-safe in-memory cache of responses keyed by request, storing the `ETag` and
+/// `Last-Modified` validators of each response so it can be revalidated with a
+/// conditional request.
+///
+/// Entries never expire, as a `304 Not Modified` response confirms the stored value is
+/// still current.
+#[derive(Debug)]
+pub struct ValidatorCache<K, V> {
+    entries: Mutex<HashMap<K, Validated<V>>>,
+}
+
+impl<K, V> Default for ValidatorCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K, V> ValidatorCache<K, V>
+where
+    K: Eq + Hash,
+    V: Clone,
+{
+    /// Returns the `If-None-Match` and `If-Modified-Since` headers to revalidate the
+    /// stored response for `key`, which are empty if there is none.
+    #[must_use]
+    pub fn conditional_headers(&self, key: &K) -> HeaderMap {
+        let entries = self.entries.lock().expect("Cache mutex poisoned");
+        let mut headers = HeaderMap::new();
+        if let Some(entry) = entries.get(key) {
+            if let Some(etag) = &entry.etag {
+                headers.insert(IF_NONE_MATCH, etag.clone());
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+            }
+        }
+        headers
+    }
+
+    /// Returns a clone of the stored value for `key`, if present.
+    #[must_use]
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.entries
+            .lock()
+            .expect("Cache mutex poisoned")
+            .get(key)
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Stores the `value` for `key` with the validators from the response `headers`,
+    /// replacing any existing entry.
+    ///
+    /// Returns whether the value was stored, which requires an `ETag` or `Last-Modified`.
+    pub fn insert(&self, key: K, headers: &HeaderMap, value: V) -> bool {
+        let etag = headers.get(ETAG).cloned();
+        let last_modified = headers.get(LAST_MODIFIED).cloned();
+        if etag.is_none() && last_modified.is_none() {
+            return false;
+        }
+
+        self.entries.lock().expect("Cache mutex poisoned").insert(
+            key,
+            Validated {
+                etag,
+                last_modified,
+                value,
+            },
+        );
+        true
+    }
+
+    /// Removes all entries from the cache.
+    pub fn clear(&self) {
+        self.entries.lock().expect("Cache mutex poisoned").clear();
+    }
+}
+
+/// A content-addressed on-disk store of instrument metadata responses, for snapshots
+/// which are reused across runs (and machines sharing the directory).
+///
+/// Each response body is stored as gzipped JSON in a file named by the SHA-256 hash of
+/// its exchange, filter and date, so a snapshot is taken at most once per day.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Creates a new [`DiskCache`] instance storing snapshots in `dir`, which is created
+    /// on the first write if it does not exist.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Returns the directory snapshots are stored in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the key of the snapshot of the instruments for `exchange` matching the
+    /// `filter` on `date`, as a hex-encoded SHA-256 hash.
+    #[must_use]
+    pub fn key(exchange: &Exchange, filter: &InstrumentFilter, date: NaiveDate) -> String {
+        let filter_json = serde_json::to_string(filter).unwrap_or_default();
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{exchange}\n{filter_json}\n{date}"));
+        hex::encode(hasher.finalize())
+    }
+
+    /// Returns the path of the file storing the snapshot for `key`.
+    #[must_use]
+    pub fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{key}.json.gz"))
+    }
+
+    /// Returns the decompressed snapshot for `key`, if present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file fails to read or decompress.
+    pub fn get(&self, key: &str) -> io::Result<Option<Vec<u8>>> {
+        let file = match fs::File::open(self.path(key)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        let mut body = Vec::new();
+        GzDecoder::new(file).read_to_end(&mut body)?;
+        Ok(Some(body))
+    }
+
+    /// Stores the `body` as the snapshot for `key`, replacing any existing snapshot.
+    ///
+    /// The file is written under a temporary name and then renamed, so concurrent readers
+    /// never see a partial snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file fails to write.
+    pub fn insert(&self, key: &str, body: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let path = self.path(key);
+        let tmp_path = path.with_extension(format!("gz.{}.tmp", uuid::Uuid::new_v4()));
+        let mut encoder = GzEncoder::new(fs::File::create(&tmp_path)?, Compression::default());
+        encoder.write_all(body)?;
+        encoder.finish()?;
+
+        fs::rename(tmp_path, path)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_disk_cache_key() {
+        let date = NaiveDate::from_ymd_opt(2025, 2, 1).unwrap();
+        let filter = InstrumentFilter::default();
+        let key = DiskCache::key(&Exchange::Deribit, &filter, date);
+
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, DiskCache::key(&Exchange::Deribit, &filter, date));
+        assert_ne!(key, DiskCache::key(&Exchange::Bitmex, &filter, date));
+        assert_ne!(
+            key,
+            DiskCache::key(&Exchange::Deribit, &filter, date.succ_opt().unwrap())
+        );
+    }
+
+    #[rstest]
+    fn test_disk_cache_get_returns_inserted_body() {
+        let dir = std::env::temp_dir().join(format!("tardis-cache-{}", uuid::Uuid::new_v4()));
+        let cache = DiskCache::new(&dir);
+
+        assert_eq!(cache.get("key").unwrap(), None);
+
+        cache.insert("key", b"[]").unwrap();
+
+        assert_eq!(cache.get("key").unwrap(), Some(b"[]".to_vec()));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[rstest]
+    fn test_get_returns_inserted_value() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        cache.insert("key", 1);
+
+        assert_eq!(cache.get(&"key"), Some(1));
+        assert_eq!(cache.get(&"other"), None);
+    }
+
+    #[rstest]
+    fn test_get_expired_entry() {
+        let cache = TtlCache::new(Duration::ZERO);
+        cache.insert("key", 1);
+
+        assert_eq!(cache.get(&"key"), None);
+    }
+
+    #[rstest]
+    fn test_clear() {
+        let cache = TtlCache::new(Duration::from_secs(60));
+        cache.insert("key", 1);
+        cache.clear();
+
+        assert_eq!(cache.get(&"key"), None);
+    }
+
+    #[rstest]
+    fn test_lookup_cache_found_and_not_found() {
+        let cache = LookupCache::new(Duration::from_secs(60), Duration::from_secs(60));
+        cache.insert("found", 1);
+        cache.insert_not_found("missing", "Not found".to_string());
+
+        assert_eq!(cache.get(&"found"), Some(Ok(1)));
+        assert_eq!(cache.get(&"missing"), Some(Err("Not found".to_string())));
+        assert_eq!(cache.get(&"other"), None);
+
+        // A found value replaces a previous not found result
+        cache.insert("missing", 2);
+        assert_eq!(cache.get(&"missing"), Some(Ok(2)));
+    }
+
+    #[rstest]
+    fn test_lookup_cache_separate_ttls() {
+        let cache = LookupCache::new(Duration::from_secs(60), Duration::ZERO);
+        cache.insert("found", 1);
+        cache.insert_not_found("missing", "Not found".to_string());
+
+        assert_eq!(cache.get(&"found"), Some(Ok(1)));
+        assert_eq!(cache.get(&"missing"), None);
+    }
+
+    #[rstest]
+    fn test_validator_cache_conditional_headers() {
+        let cache = ValidatorCache::default();
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"v1\""));
+        headers.insert(
+            LAST_MODIFIED,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert!(cache.insert("key", &headers, 1));
+
+        let conditional = cache.conditional_headers(&"key");
+        assert_eq!(conditional.get(IF_NONE_MATCH).unwrap(), "\"v1\"");
+        assert_eq!(
+            conditional.get(IF_MODIFIED_SINCE).unwrap(),
+            "Wed, 21 Oct 2015 07:28:00 GMT"
+        );
+        assert_eq!(cache.get(&"key"), Some(1));
+        assert!(cache.conditional_headers(&"other").is_empty());
+    }
+
+    #[rstest]
+    fn test_validator_cache_requires_validator() {
+        let cache = ValidatorCache::default();
+
+        assert!(!cache.insert("key", &HeaderMap::new(), 1));
+        assert_eq!(cache.get(&"key"), None);
+    }
+}
@@ -13,208 +13,7253 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{env, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    env,
+    fmt::{self, Debug},
+    fs,
+    future::Future,
+    io::Read,
+    num::{NonZeroU32, NonZeroUsize},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
-use nautilus_core::{consts::USER_AGENT, UnixNanos};
-use nautilus_model::instruments::InstrumentAny;
-use reqwest::Response;
+use async_stream::stream;
+use chrono::{NaiveDate, Utc};
+use futures_util::{future, stream, Stream, StreamExt, TryStreamExt};
+use nautilus_core::{consts::USER_AGENT, UnixNanos, UUID4};
+use nautilus_model::{
+    identifiers::{InstrumentId, Symbol},
+    instruments::InstrumentAny,
+    types::Currency,
+};
+use nautilus_serialization::parquet::write_batch_to_parquet;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Response, StatusCode,
+};
+use serde::{
+    de::{self, DeserializeOwned, SeqAccess, Visitor},
+    Deserialize, Deserializer as _,
+};
+use strum::IntoEnumIterator;
+use tokio::{
+    io::AsyncBufReadExt,
+    sync::{mpsc, watch},
+    task::JoinHandle,
+    time::MissedTickBehavior,
+};
+use tokio_util::{
+    io::{StreamReader, SyncIoBridge},
+    sync::CancellationToken,
+};
+use tracing::Instrument;
 
+#[cfg(feature = "vcr")]
+use super::vcr::{Vcr, VcrMode};
 use super::{
-    error::{Error, TardisErrorResponse},
-    models::InstrumentInfo,
-    parse::parse_instrument_any,
-    query::InstrumentFilter,
-    TARDIS_BASE_URL,
+    breaker::CircuitBreaker,
+    cache::{DiskCache, LookupCache, TtlCache, ValidatorCache},
+    error::{parse_error_body, Error, ErrorBodyParser, RESPONSE_SNIPPET_MAX_LEN},
+    export::{instruments_to_record_batch, InstrumentRecord},
+    keys::{read_api_key_file, ApiKeyPool, ApiKeyProvider, AuthStyle},
+    metrics::TardisMetrics,
+    models::{ArrayLen, ExchangeDetails, InstrumentInfo, InstrumentSymbol},
+    parse::{as_instrument, parse_datetime_to_unix_nanos, parse_instrument_any_with_normalizer},
+    query::{InstrumentFilter, InstrumentOptions},
+    ratelimit::{parse_retry_after, ConcurrencyLimit, RateLimitState, TokenBucket},
+    replay::{parse_replay_line, ReplayMessage, ReplayRequest},
+    retry::{RetryConfig, RetryCounters, RetryStats, TardisEndpoint},
+    TardisEnv,
+};
+use crate::{
+    enums::{Exchange, InstrumentType},
+    parse::{
+        denormalize_symbol_str, DefaultSymbolNormalizer, RawSymbolNormalizer, SymbolNormalizer,
+    },
 };
-use crate::enums::Exchange;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+const API_KEY_MASK: &str = "****";
+
+/// The number of matching elements a filtered response body is read ahead of the consumer.
+const FILTERED_READ_AHEAD: usize = 16;
+
+/// The header pinning the Tardis API version of a request.
+pub const HEADER_API_VERSION: &str = "Api-Version";
+
+/// The header holding a key generated per logical request, which is the same for each
+/// retry attempt, so attempts of the same call can be recognized.
+pub const HEADER_IDEMPOTENCY_KEY: &str = "Idempotency-Key";
+
+/// The maximum number of redirects followed for a request.
+pub const MAX_REDIRECTS: usize = 5;
+
+/// The default maximum size of a response body in bytes (256 MiB), well above the largest
+/// instrument metadata responses.
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 256 * 1024 * 1024;
+
+/// A predicate over parsed instruments, used to filter on properties which the Tardis
+/// instruments filter does not support.
+pub type InstrumentPredicate = dyn Fn(&InstrumentAny) -> bool + Send + Sync;
+
+type InstrumentsInfoCache = TtlCache<(Exchange, InstrumentFilter), Vec<InstrumentInfo>>;
+type InstrumentInfoCache = LookupCache<(Exchange, String), InstrumentInfo, (u64, String)>;
+type InstrumentsInfoValidators = ValidatorCache<(Exchange, InstrumentFilter), Vec<InstrumentInfo>>;
+
+/// The connection pool and keep-alive options for the underlying HTTP client.
+///
+/// Unset options use the `reqwest` defaults (unlimited idle connections per host,
+/// a 90 second idle timeout and no TCP keep-alive).
+#[derive(Debug, Clone, Copy, Default)]
+struct PoolConfig {
+    max_idle_per_host: Option<usize>,
+    idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+}
+
+/// Metadata about a Tardis HTTP API response, for diagnosing slow or unexpected responses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// The final URL of the request (after any redirects).
+    pub url: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The elapsed time from sending the request (including any retries) until the
+    /// response body was received.
+    pub elapsed: Duration,
+    /// The size of the decoded response body in bytes.
+    pub size_bytes: usize,
+}
+
+/// A Tardis instrument definition which could not be parsed into a Nautilus instrument.
+#[derive(Debug)]
+pub struct SkippedInstrument {
+    /// The Tardis instrument definition.
+    pub info: InstrumentInfo,
+    /// The reason the definition could not be parsed.
+    pub error: anyhow::Error,
+}
+
+/// The mutable state of a client, shared between all of its clones through a single
+/// `Arc`, so that clones used across tasks draw from the same rate limit budget, caches
+/// and circuit breaker.
+#[derive(Default)]
+struct SharedState {
+    retry_counters: RetryCounters,
+    rate_limit: RateLimitState,
+    request_limiter: Option<TokenBucket>,
+    concurrency_limit: Option<ConcurrencyLimit>,
+    instruments_cache: Option<InstrumentsInfoCache>,
+    instruments_validators: Option<InstrumentsInfoValidators>,
+    instrument_cache: Option<InstrumentInfoCache>,
+    circuit_breaker: Option<CircuitBreaker>,
+}
+
+impl SharedState {
+    /// Returns new empty state with the same configuration, sharing nothing with `self`.
+    fn detached(&self) -> Self {
+        Self {
+            retry_counters: RetryCounters::default(),
+            rate_limit: RateLimitState::default(),
+            request_limiter: self
+                .request_limiter
+                .as_ref()
+                .map(|limiter| TokenBucket::new(limiter.requests_per_second())),
+            concurrency_limit: self
+                .concurrency_limit
+                .as_ref()
+                .map(|limit| ConcurrencyLimit::new(limit.max_concurrent_requests())),
+            instruments_cache: self
+                .instruments_cache
+                .as_ref()
+                .map(|cache| TtlCache::new(cache.ttl())),
+            instruments_validators: self
+                .instruments_validators
+                .as_ref()
+                .map(|_| ValidatorCache::default()),
+            instrument_cache: self
+                .instrument_cache
+                .as_ref()
+                .map(|cache| LookupCache::new(cache.ttl(), cache.not_found_ttl())),
+            circuit_breaker: self
+                .circuit_breaker
+                .as_ref()
+                .map(|breaker| CircuitBreaker::new(breaker.threshold(), breaker.cooldown())),
+        }
+    }
+}
+
+/// The TLS options for the underlying HTTP client.
+#[derive(Clone, Default)]
+struct TlsConfig {
+    root_certificates: Vec<reqwest::Certificate>,
+    accept_invalid_certs: bool,
+}
+
 /// A Tardis HTTP API client.
 /// See <https://docs.tardis.dev/api/http>.
+///
+/// Responses are requested with gzip or brotli compression and transparently decoded,
+/// which typically reduces instrument metadata payloads (multiple megabytes of JSON for
+/// the major exchanges) by around 90% over the wire.
+///
+/// Clones are cheap and share the rate limit budget, caches, circuit breaker and retry
+/// statistics, so a client can be cloned into each task which sends requests. Enabling
+/// any of these on a clone gives it new state of its own.
+///
+/// Up to [`MAX_REDIRECTS`] redirects are followed, failing with [`Error::Redirect`] on a
+/// redirect loop. Redirects within the same origin keep the API key, while the
+/// `Authorization` header is dropped on cross-origin redirects (so the target responds
+/// with a 401 unless it needs no key). Cross-origin redirects are not followed when the
+/// key is sent in a custom header, which would otherwise be forwarded. This policy does
+/// not apply to a client supplied with [`TardisHttpClientBuilder::client`].
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.adapters")
 )]
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TardisHttpClient {
     base_url: String,
-    api_key: String,
+    api_keys: Arc<ApiKeyPool>,
+    auth_header: Option<HeaderName>,
+    api_version: Option<String>,
+    timeout: Duration,
+    user_agent: String,
+    default_headers: HeaderMap,
+    pool_config: PoolConfig,
+    tls_config: TlsConfig,
     client: reqwest::Client,
     normalize_symbols: bool,
+    symbol_normalizer: Option<Arc<dyn SymbolNormalizer>>,
+    dedup_instruments: bool,
+    validate_filter: bool,
+    buffer_responses: bool,
+    max_response_bytes: Option<usize>,
+    retry_config: Option<RetryConfig>,
+    endpoint_retry_configs: HashMap<TardisEndpoint, RetryConfig>,
+    disk_cache: Option<DiskCache>,
+    state: Arc<SharedState>,
+    metrics: Option<Arc<dyn TardisMetrics>>,
+    error_parser: Option<Arc<dyn ErrorBodyParser>>,
+    slow_request_threshold: Option<Duration>,
+    deadline: Option<Instant>,
+    #[cfg(feature = "vcr")]
+    vcr: Option<Arc<Vcr>>,
+}
+
+impl Debug for TardisHttpClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The underlying client is omitted as its proxy configuration may contain credentials
+        f.debug_struct(stringify!(TardisHttpClient))
+            .field("base_url", &self.base_url)
+            .field("api_key", &API_KEY_MASK)
+            .field("active_key_index", &self.active_key_index())
+            .field("auth_header", &self.auth_header)
+            .field("api_version", &self.api_version)
+            .field("timeout", &self.timeout)
+            .field("user_agent", &self.user_agent)
+            .field("pool_config", &self.pool_config)
+            .field(
+                "accept_invalid_certs",
+                &self.tls_config.accept_invalid_certs,
+            )
+            .field("normalize_symbols", &self.normalize_symbols)
+            .field(
+                "custom_symbol_normalizer",
+                &self.symbol_normalizer.is_some(),
+            )
+            .field("dedup_instruments", &self.dedup_instruments)
+            .field("validate_filter", &self.validate_filter)
+            .field("buffer_responses", &self.buffer_responses)
+            .field("max_response_bytes", &self.max_response_bytes)
+            .field("retry_config", &self.retry_config)
+            .field("endpoint_retry_configs", &self.endpoint_retry_configs)
+            .field("retry_stats", &self.retry_stats())
+            .field("request_limiter", &self.state.request_limiter)
+            .field(
+                "max_concurrent_requests",
+                &self
+                    .state
+                    .concurrency_limit
+                    .as_ref()
+                    .map(ConcurrencyLimit::max_concurrent_requests),
+            )
+            .field("circuit_breaker", &self.state.circuit_breaker)
+            .field("custom_error_parser", &self.error_parser.is_some())
+            .field("slow_request_threshold", &self.slow_request_threshold)
+            .field("deadline", &self.deadline)
+            .field("remaining_requests", &self.remaining_requests())
+            .field(
+                "cache_ttl",
+                &self
+                    .state
+                    .instruments_cache
+                    .as_ref()
+                    .map(|cache| cache.ttl()),
+            )
+            .field(
+                "conditional_requests",
+                &self.state.instruments_validators.is_some(),
+            )
+            .field(
+                "disk_cache_dir",
+                &self.disk_cache.as_ref().map(DiskCache::dir),
+            )
+            .field(
+                "instrument_cache_ttls",
+                &self
+                    .state
+                    .instrument_cache
+                    .as_ref()
+                    .map(|cache| (cache.ttl(), cache.not_found_ttl())),
+            )
+            .finish_non_exhaustive()
+    }
 }
 
 impl TardisHttpClient {
     /// Creates a new [`TardisHttpClient`] instance.
+    ///
+    /// See [`TardisHttpClientBuilder`] for further configuration options.
     pub fn new(
         api_key: Option<&str>,
         base_url: Option<&str>,
         timeout_secs: Option<u64>,
         normalize_symbols: bool,
     ) -> anyhow::Result<Self> {
-        let api_key = match api_key {
-            Some(key) => key.to_string(),
-            None => env::var("TARDIS_API_KEY").map_err(|_| {
-                anyhow::anyhow!(
-                    "API key must be provided or set in the 'TARDIS_API_KEY' environment variable"
-                )
-            })?,
-        };
+        let mut builder = Self::builder().normalize_symbols(normalize_symbols);
+        if let Some(api_key) = api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(base_url) = base_url {
+            builder = builder.base_url(base_url);
+        }
+        if let Some(timeout_secs) = timeout_secs {
+            builder = builder.timeout(Duration::from_secs(timeout_secs));
+        }
+        builder.build()
+    }
+
+    /// Creates a new [`TardisHttpClient`] instance which sends requests with the given
+    /// pre-built `client`, such as one shared across a service.
+    ///
+    /// The `client` is used as is, so its own timeout, connection pool and default headers apply.
+    pub fn with_client(
+        client: reqwest::Client,
+        api_key: Option<&str>,
+        base_url: Option<&str>,
+        normalize_symbols: bool,
+    ) -> anyhow::Result<Self> {
+        let mut builder = Self::builder()
+            .client(client)
+            .normalize_symbols(normalize_symbols);
+        if let Some(api_key) = api_key {
+            builder = builder.api_key(api_key);
+        }
+        if let Some(base_url) = base_url {
+            builder = builder.base_url(base_url);
+        }
+        builder.build()
+    }
 
-        let base_url = base_url.map_or_else(|| TARDIS_BASE_URL.to_string(), ToString::to_string);
-        let timeout = timeout_secs.map_or_else(|| Duration::from_secs(60), Duration::from_secs);
+    /// Returns a new [`TardisHttpClientBuilder`] with default options.
+    #[must_use]
+    pub fn builder() -> TardisHttpClientBuilder {
+        TardisHttpClientBuilder::default()
+    }
 
-        let client = reqwest::Client::builder()
-            .user_agent(USER_AGENT)
+    fn build_client(
+        timeout: Duration,
+        user_agent: &str,
+        default_headers: HeaderMap,
+        pool_config: PoolConfig,
+        tls_config: &TlsConfig,
+        proxy: Option<reqwest::Proxy>,
+        custom_auth_header: bool,
+    ) -> anyhow::Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .user_agent(user_agent)
+            .default_headers(default_headers)
+            .redirect(redirect_policy(custom_auth_header))
             .timeout(timeout)
-            .build()?;
+            .tcp_keepalive(pool_config.tcp_keepalive)
+            .gzip(true)
+            .brotli(true);
 
-        Ok(Self {
-            base_url,
-            api_key,
-            client,
-            normalize_symbols,
-        })
+        if let Some(max_idle) = pool_config.max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max_idle);
+        }
+        if let Some(idle_timeout) = pool_config.idle_timeout {
+            builder = builder.pool_idle_timeout(idle_timeout);
+        }
+
+        for certificate in &tls_config.root_certificates {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if tls_config.accept_invalid_certs {
+            tracing::warn!("TLS certificate validation is disabled");
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(proxy);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    fn build_proxy(
+        proxy_url: &str,
+        credentials: Option<(&str, &str)>,
+    ) -> anyhow::Result<reqwest::Proxy> {
+        let mut proxy = reqwest::Proxy::all(proxy_url)?;
+        if let Some((username, password)) = credentials {
+            proxy = proxy.basic_auth(username, password);
+        }
+        Ok(proxy)
+    }
+
+    /// Routes all requests through the HTTP(S) proxy at `proxy_url`, optionally
+    /// authenticating with the given `(username, password)` credentials.
+    ///
+    /// When no proxy is configured, the standard `HTTP_PROXY` and `HTTPS_PROXY`
+    /// environment variables are respected.
+    pub fn with_proxy(
+        mut self,
+        proxy_url: &str,
+        credentials: Option<(&str, &str)>,
+    ) -> anyhow::Result<Self> {
+        let proxy = Self::build_proxy(proxy_url, credentials)?;
+        self.client = Self::build_client(
+            self.timeout,
+            &self.user_agent,
+            self.default_headers.clone(),
+            self.pool_config,
+            &self.tls_config,
+            Some(proxy),
+            self.auth_header.is_some(),
+        )?;
+        Ok(self)
+    }
+
+    /// Enables retrying of transient failures with exponential backoff and jitter.
+    ///
+    /// Only HTTP 429, 500, 502, 503, 504 responses and request timeouts are retried,
+    /// up to `max_retries` times after the initial attempt.
+    #[must_use]
+    pub const fn with_retries(
+        mut self,
+        max_retries: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+    ) -> Self {
+        self.retry_config = Some(RetryConfig::new(max_retries, base_delay, max_delay));
+        self
+    }
+
+    /// Retries transient failures of requests to `endpoint` with the given `retry_config`,
+    /// in place of the client-wide configuration set with [`Self::with_retries`].
+    ///
+    /// This allows cheap lookups (such as [`TardisEndpoint::InstrumentInfo`]) to be retried
+    /// more aggressively than expensive bulk requests, with the backoff capped at the
+    /// `max_delay` of each configuration.
+    #[must_use]
+    pub fn with_endpoint_retries(
+        mut self,
+        endpoint: TardisEndpoint,
+        retry_config: RetryConfig,
+    ) -> Self {
+        self.endpoint_retry_configs.insert(endpoint, retry_config);
+        self
+    }
+
+    /// Pins the Tardis API version by sending it in the [`HEADER_API_VERSION`] header of
+    /// every request, protecting against breaking changes to the response shape.
+    #[must_use]
+    pub fn with_api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes, where a `max_response_bytes` of
+    /// zero removes the limit.
+    ///
+    /// Reading a larger body (such as from a misconfigured base URL) is aborted with
+    /// [`Error::ResponseTooLarge`] once the limit is exceeded, rather than buffering it.
+    /// Defaults to [`DEFAULT_MAX_RESPONSE_BYTES`].
+    #[must_use]
+    pub fn with_max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes).filter(|limit| *limit > 0);
+        self
+    }
+
+    /// Caps the rate of requests sent by the client (and its clones) at
+    /// `requests_per_second`, with bursts of up to one second of requests.
+    ///
+    /// Every request waits for a token from a shared token bucket before it is sent, which
+    /// keeps the client under the Tardis rate limits before any 429 is returned.
+    /// A `requests_per_second` of zero removes the cap.
+    #[must_use]
+    pub fn with_rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.state_mut().request_limiter =
+            NonZeroU32::new(requests_per_second).map(TokenBucket::new);
+        self
+    }
+
+    /// Caps the number of requests in flight at once at `max_concurrent_requests`, across
+    /// all methods and clones of the client.
+    ///
+    /// Each request (and each retry) waits for a permit before it is sent, holding it until
+    /// the response is received. A `max_concurrent_requests` of zero removes the cap.
+    #[must_use]
+    pub fn with_max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.state_mut().concurrency_limit =
+            NonZeroUsize::new(max_concurrent_requests).map(ConcurrencyLimit::new);
+        self
+    }
+
+    /// Enables in-memory caching of [`Self::instruments_info`] responses for the given `ttl`.
+    ///
+    /// Responses are cached per exchange and filter, and the cache is shared between clones.
+    #[must_use]
+    pub fn with_cache(mut self, ttl: Duration) -> Self {
+        self.state_mut().instruments_cache = Some(TtlCache::new(ttl));
+        self
+    }
+
+    /// Sets a custom `normalizer` mapping raw Tardis symbols to the symbols of Nautilus
+    /// instrument IDs, in place of the built-in [`DefaultSymbolNormalizer`].
+    ///
+    /// The normalizer is only applied when symbols are normalized (see
+    /// [`TardisHttpClientBuilder::normalize_symbols`]), otherwise the raw symbols are kept.
+    #[must_use]
+    pub fn with_symbol_normalizer(mut self, normalizer: Arc<dyn SymbolNormalizer>) -> Self {
+        self.symbol_normalizer = Some(normalizer);
+        self
+    }
+
+    /// Returns the state of the client for reconfiguring, first detaching it from the
+    /// state shared with any clones.
+    fn state_mut(&mut self) -> &mut SharedState {
+        if Arc::get_mut(&mut self.state).is_none() {
+            self.state = Arc::new(self.state.detached());
+        }
+        Arc::get_mut(&mut self.state).expect("State was detached from clones")
+    }
+
+    /// Returns the symbol normalizer to parse instruments with, where the optional
+    /// `normalize_symbols` overrides the client setting.
+    fn normalizer(&self, normalize_symbols: Option<bool>) -> &dyn SymbolNormalizer {
+        if !normalize_symbols.unwrap_or(self.normalize_symbols) {
+            return &RawSymbolNormalizer;
+        }
+        match &self.symbol_normalizer {
+            Some(normalizer) => normalizer.as_ref(),
+            None => &DefaultSymbolNormalizer,
+        }
+    }
+
+    /// Enables a circuit breaker which opens after `threshold` consecutive failures of the
+    /// Tardis API (transport and server errors), then fails requests fast with
+    /// [`Error::CircuitOpen`] until the `cooldown` has elapsed.
+    ///
+    /// A single probe request is then sent: if it succeeds the circuit closes, otherwise
+    /// requests fail fast for another `cooldown`. The state is shared between clones.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.state_mut().circuit_breaker = Some(CircuitBreaker::new(threshold, cooldown));
+        self
+    }
+
+    /// Enables in-memory caching of [`Self::instrument_info`] lookups per exchange and
+    /// symbol, where found instruments are cached for `ttl`, and symbols which are not
+    /// found (a 404 response) for `not_found_ttl`.
+    ///
+    /// Caching symbols which are not found means repeated lookups of a missing symbol
+    /// return [`Error::NotFound`] without spending requests from the rate limit budget.
+    /// A short `not_found_ttl` limits how long a newly listed symbol stays unavailable.
+    /// The cache is shared between clones.
+    #[must_use]
+    pub fn with_instrument_cache(mut self, ttl: Duration, not_found_ttl: Duration) -> Self {
+        self.state_mut().instrument_cache = Some(LookupCache::new(ttl, not_found_ttl));
+        self
+    }
+
+    /// Enables read-through and write-through caching of [`Self::instruments_info`]
+    /// responses to the on-disk `disk_cache`, keyed by exchange, filter and the current
+    /// UTC date.
+    ///
+    /// A snapshot already stored for the day is returned without a request, so repeated
+    /// runs (including on other machines sharing the directory) reuse the same metadata.
+    /// This complements [`Self::with_cache`], which is checked first.
+    #[must_use]
+    pub fn with_disk_cache(mut self, disk_cache: DiskCache) -> Self {
+        self.disk_cache = Some(disk_cache);
+        self
+    }
+
+    /// Enables conditional requests for [`Self::instruments_info`].
+    ///
+    /// The `ETag` and `Last-Modified` validators returned with instrument metadata are
+    /// stored (along with the parsed response) per exchange and filter, and sent back as
+    /// `If-None-Match` and `If-Modified-Since` headers. When the API responds with
+    /// `304 Not Modified`, the stored response is returned without downloading or parsing
+    /// the metadata again. This complements [`Self::with_cache`], revalidating cheaply once
+    /// a cached response expires. Responses without validators are not stored.
+    #[must_use]
+    pub fn with_conditional_requests(mut self) -> Self {
+        self.state_mut().instruments_validators = Some(ValidatorCache::default());
+        self
+    }
+
+    /// Sets an overall `deadline` for requests made by the client, bounding the total
+    /// time spent requesting, retrying, waiting on rate limits and paging.
+    ///
+    /// Once the deadline passes, in-flight requests are abandoned and methods return
+    /// [`Error::DeadlineExceeded`], as does a retry whose delay would end after it. As the
+    /// deadline is absolute, it is typically set on a clone for a single logical fetch:
+    /// `client.clone().with_deadline(Instant::now() + Duration::from_secs(5))`.
+    #[must_use]
+    pub const fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Runs the request `fut` until the client deadline (if any) passes.
+    async fn until_deadline<T>(&self, fut: impl Future<Output = Result<T>>) -> Result<T> {
+        let Some(deadline) = self.deadline else {
+            return fut.await;
+        };
+
+        tokio::time::timeout_at(deadline.into(), fut)
+            .await
+            .map_err(|_| {
+                tracing::warn!("Request deadline exceeded");
+                Error::DeadlineExceeded
+            })?
+    }
+
+    /// Records requests to, or plays responses back from, the cassette of the `vcr`
+    /// (see [`Vcr`]), such as for deterministic integration tests.
+    #[cfg(feature = "vcr")]
+    #[must_use]
+    pub fn with_vcr(mut self, vcr: Arc<Vcr>) -> Self {
+        self.vcr = Some(vcr);
+        self
+    }
+
+    /// Registers `metrics` callbacks which are invoked around each HTTP request.
+    #[must_use]
+    pub fn with_metrics(mut self, metrics: Arc<dyn TardisMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sets a custom `error_parser` for the bodies of unsuccessful responses, such as for a
+    /// self-hosted Tardis-compatible gateway with its own error schema.
+    ///
+    /// Bodies the parser does not recognize fall back to the built-in parsing, which
+    /// handles the Tardis error schema and several common gateway shapes.
+    #[must_use]
+    pub fn with_error_parser(mut self, error_parser: Arc<dyn ErrorBodyParser>) -> Self {
+        self.error_parser = Some(error_parser);
+        self
+    }
+
+    /// Logs a warning with the endpoint and elapsed time for each request whose response
+    /// takes longer than `threshold` to arrive, to catch latency regressions without
+    /// enabling debug logging.
+    #[must_use]
+    pub const fn with_slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
     }
 
-    async fn handle_error_response<T>(resp: Response) -> Result<T> {
+    /// Clears all cached responses, forcing subsequent requests to refresh from the API.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.state.instruments_cache {
+            cache.clear();
+        }
+        if let Some(validators) = &self.state.instruments_validators {
+            validators.clear();
+        }
+        if let Some(cache) = &self.state.instrument_cache {
+            cache.clear();
+        }
+    }
+
+    /// Returns the index of the API key currently used to authenticate requests.
+    ///
+    /// The index changes when the active key is rate limited or rejected and further keys
+    /// were provided with [`TardisHttpClientBuilder::api_keys`].
+    #[must_use]
+    pub fn active_key_index(&self) -> usize {
+        self.api_keys.active_index()
+    }
+
+    /// Returns the retry counters of this client and its clones.
+    #[must_use]
+    pub fn retry_stats(&self) -> RetryStats {
+        self.state.retry_counters.stats()
+    }
+
+    /// Returns the number of remaining requests last reported by the Tardis API (if known).
+    #[must_use]
+    pub fn remaining_requests(&self) -> Option<u32> {
+        self.state.rate_limit.remaining()
+    }
+
+    async fn handle_error_response<T>(&self, resp: Response) -> Result<T> {
         let status = resp.status().as_u16();
+        let retry_after = parse_retry_after(resp.headers());
         let error_text = resp.text().await.unwrap_or_default();
 
-        if let Ok(error) = serde_json::from_str::<TardisErrorResponse>(&error_text) {
-            Err(Error::ApiError {
-                status,
-                code: error.code,
-                message: error.message,
-            })
-        } else {
-            Err(Error::ApiError {
-                status,
-                code: 0,
-                message: error_text,
-            })
-        }
+        let (code, message) = self
+            .error_parser
+            .as_ref()
+            .and_then(|parser| parser.parse(status, &error_text))
+            .unwrap_or_else(|| parse_error_body(status, &error_text));
+
+        Err(Error::from_status(status, code, message, retry_after))
     }
 
-    /// Returns all Tardis instrument definitions for the given `exchange`.
+    /// Sends a GET request for `url` with the additional `headers`, unless the circuit
+    /// breaker is open.
     ///
-    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
-    pub async fn instruments_info(
+    /// A `304 Not Modified` is returned as a response (rather than an error), as it is
+    /// only sent in reply to conditional `headers`.
+    async fn send(
         &self,
-        exchange: Exchange,
-        filter: Option<&InstrumentFilter>,
-    ) -> Result<Vec<InstrumentInfo>> {
-        let mut url = format!("{}/instruments/{exchange}", &self.base_url);
-        if let Some(filter) = filter {
-            if let Ok(filter_json) = serde_json::to_string(filter) {
-                url.push_str(&format!("?filter={}", urlencoding::encode(&filter_json)));
+        url: &str,
+        headers: &HeaderMap,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let Some(breaker) = &self.state.circuit_breaker else {
+            return self.send_request(url, headers, timeout).await;
+        };
+
+        breaker
+            .try_acquire()
+            .map_err(|retry_in| Error::CircuitOpen { retry_in })?;
+        let result = self.send_request(url, headers, timeout).await;
+        breaker.record(&result);
+        result
+    }
+
+    async fn send_request(
+        &self,
+        url: &str,
+        headers: &HeaderMap,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let _permit = match &self.state.concurrency_limit {
+            Some(limit) => Some(limit.acquire().await),
+            None => None,
+        };
+
+        if let Some(limiter) = &self.state.request_limiter {
+            let delay = limiter.acquire();
+            if !delay.is_zero() {
+                tracing::debug!("Request rate limit reached, waiting {delay:?}");
+                tokio::time::sleep(delay).await;
             }
         }
-        tracing::debug!("Requesting: {url}");
 
-        let resp = self
-            .client
-            .get(url)
-            .bearer_auth(&self.api_key)
-            .send()
-            .await?;
-        tracing::debug!("Response status: {}", resp.status());
+        if let Some(delay) = self.state.rate_limit.wait_duration() {
+            tracing::debug!("Rate limit budget exhausted, waiting {delay:?}");
+            tokio::time::sleep(delay).await;
+        }
 
-        if !resp.status().is_success() {
-            return Self::handle_error_response(resp).await;
+        let (key_index, api_key, mut request) = self.request_builder(url)?;
+        request = request.headers(headers.clone());
+        if let Some(timeout) = timeout {
+            request = request.timeout(timeout);
         }
 
-        let body = resp.text().await?;
-        tracing::trace!("{body}");
+        let endpoint = self.endpoint(url);
+        if let Some(metrics) = &self.metrics {
+            metrics.on_request(endpoint);
+        }
 
-        match serde_json::from_str(&body) {
-            Ok(parsed) => Ok(parsed),
+        let start = Instant::now();
+        let mut resp = match self.execute(url, request).await {
+            Ok(resp) => resp,
             Err(e) => {
-                tracing::error!("Failed to parse response: {}", e);
-                tracing::debug!("Response body was: {}", body);
-                Err(Error::ResponseParse(e.to_string()))
+                if let Some(metrics) = &self.metrics {
+                    metrics.on_error(endpoint);
+                }
+                return Err(e);
+            }
+        };
+        tracing::debug!("Response status: {}", resp.status());
+        resp.extensions_mut().insert(SentApiKey(api_key));
+
+        let elapsed = start.elapsed();
+        if let Some(metrics) = &self.metrics {
+            metrics.on_response(endpoint, resp.status().as_u16(), elapsed);
+        }
+        if self
+            .slow_request_threshold
+            .is_some_and(|threshold| elapsed > threshold)
+        {
+            tracing::warn!("Slow request to {endpoint}: response after {elapsed:?}");
+        }
+
+        self.state.rate_limit.update(resp.headers());
+
+        if !resp.status().is_success() && resp.status() != StatusCode::NOT_MODIFIED {
+            if let Some(metrics) = &self.metrics {
+                metrics.on_error(endpoint);
             }
+            if matches!(resp.status().as_u16(), 401 | 429) {
+                self.api_keys.advance_from(key_index);
+            }
+            return self.handle_error_response(resp).await;
         }
+
+        Ok(resp)
     }
 
-    /// Returns the Tardis instrument definition for a given `exchange` and `symbol`.
-    ///
-    /// See <https://docs.tardis.dev/api/instruments-metadata-api#single-instrument-info-endpoint>.
-    pub async fn instrument_info(
+    /// Sends the `request` for `url`, or when a VCR is set, records the response to or
+    /// plays it back from the cassette.
+    #[cfg_attr(not(feature = "vcr"), allow(unused_variables))]
+    async fn execute(&self, url: &str, request: reqwest::RequestBuilder) -> Result<Response> {
+        #[cfg(feature = "vcr")]
+        if let Some(vcr) = &self.vcr {
+            let path = url.strip_prefix(self.base_url.as_str()).unwrap_or(url);
+            return match vcr.mode() {
+                VcrMode::Record => {
+                    let resp = request.send().await.map_err(redirect_error)?;
+                    let status = resp.status();
+                    let headers = resp.headers().clone();
+                    let body = self.read_body(resp).await?;
+                    vcr.record_response("GET", path, status, &headers, &body)
+                        .await
+                }
+                VcrMode::Playback => vcr.play("GET", path),
+            };
+        }
+
+        request.send().await.map_err(redirect_error)
+    }
+
+    /// Returns the request path of `url` relative to the base URL, without the query.
+    fn endpoint<'a>(&self, url: &'a str) -> &'a str {
+        let path = url.strip_prefix(self.base_url.as_str()).unwrap_or(url);
+        path.split('?').next().unwrap_or(path)
+    }
+
+    async fn send_with_retries(
         &self,
-        exchange: Exchange,
-        symbol: &str,
-    ) -> Result<InstrumentInfo> {
-        let url = format!("{}/instruments/{exchange}/{symbol}", &self.base_url);
-        tracing::debug!("Requesting {url}");
+        url: &str,
+        headers: &HeaderMap,
+        timeout: Option<Duration>,
+    ) -> Result<Response> {
+        let idempotency_key = UUID4::new().to_string();
+        tracing::trace!("Idempotency key: {idempotency_key}");
+        let mut headers = headers.clone();
+        headers.insert(
+            HEADER_IDEMPOTENCY_KEY,
+            HeaderValue::from_str(&idempotency_key).expect("UUID is a valid header value"),
+        );
+        let headers = &headers;
 
-        let resp = self
-            .client
-            .get(url)
-            .bearer_auth(&self.api_key)
-            .send()
-            .await?;
-        tracing::debug!("Response status: {}", resp.status());
+        let retry_config = TardisEndpoint::from_path(self.endpoint(url))
+            .and_then(|endpoint| self.endpoint_retry_configs.get(&endpoint))
+            .or(self.retry_config.as_ref());
+        let Some(retry_config) = retry_config else {
+            self.state.retry_counters.on_attempt(1);
+            return self.send(url, headers, timeout).await;
+        };
 
-        if !resp.status().is_success() {
-            return Self::handle_error_response(resp).await;
-        }
+        let mut backoff = retry_config.backoff();
+        let mut attempts = 0;
 
-        let body = resp.text().await?;
-        tracing::trace!("{body}");
+        loop {
+            attempts += 1;
+            self.state.retry_counters.on_attempt(attempts);
 
-        match serde_json::from_str(&body) {
-            Ok(parsed) => Ok(parsed),
-            Err(e) => {
-                tracing::error!("Failed to parse response: {}", e);
-                tracing::debug!("Response body was: {}", body);
-                Err(Error::ResponseParse(e.to_string()))
+            match self.send(url, headers, timeout).await {
+                Ok(resp) => return Ok(resp),
+                Err(e) if e.is_retryable() => {
+                    if attempts > retry_config.max_retries {
+                        self.state.retry_counters.on_exhausted();
+                        return Err(Error::RetriesExhausted {
+                            attempts,
+                            source: Box::new(e),
+                        });
+                    }
+
+                    let backoff_delay = backoff.next_duration();
+                    let delay = e.retry_after().unwrap_or(backoff_delay);
+                    if self
+                        .deadline
+                        .is_some_and(|deadline| Instant::now() + delay > deadline)
+                    {
+                        tracing::warn!(
+                            "Request failed (attempt {attempts}): {e}, retry in {delay:?} would exceed deadline"
+                        );
+                        return Err(Error::DeadlineExceeded);
+                    }
+                    tracing::warn!(
+                        "Request failed (attempt {attempts}): {e}, retrying in {delay:?}"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e),
             }
         }
     }
 
-    /// Returns all Nautilus instrument definitions for the given `exchange`.
-    ///
-    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
-    pub async fn instruments(
+    /// Requests `url` and buffers the response body, returning it along with the response
+    /// metadata and the secrets to mask in errors describing the body.
+    async fn get_body(
         &self,
-        exchange: Exchange,
-        start: Option<u64>,
-        end: Option<u64>,
-        ts_init: Option<u64>,
-        filter: Option<&InstrumentFilter>,
-    ) -> Result<Vec<InstrumentAny>> {
-        let response = self.instruments_info(exchange, filter).await?;
-        let ts_init = ts_init.map(UnixNanos::from);
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<u8>, ResponseMeta, Vec<String>)> {
+        self.until_deadline(async {
+            let start = Instant::now();
+            let resp = self
+                .send_with_retries(url, &HeaderMap::new(), timeout)
+                .await?;
+            let final_url = resp.url().to_string();
+            let status = resp.status().as_u16();
+            let secrets = self.response_secrets(&resp);
 
-        Ok(response
-            .into_iter()
-            .flat_map(|info| {
-                parse_instrument_any(info, start, end, ts_init, self.normalize_symbols)
-            })
-            .collect())
+            let body = self.read_body(resp).await?;
+            tracing::trace!("{}", String::from_utf8_lossy(&body));
+
+            let meta = ResponseMeta {
+                url: final_url,
+                status,
+                elapsed: start.elapsed(),
+                size_bytes: body.len(),
+            };
+            Ok((body, meta, secrets))
+        })
+        .await
     }
 
-    /// Returns a Nautilus instrument definition for the given `exchange` and `symbol`.
+    /// Requests `url` and deserializes the response body as JSON while it is received,
+    /// rather than buffering the full body first (unless responses are buffered).
+    async fn get_json<T: DeserializeOwned + Send + 'static>(
+        &self,
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<T> {
+        self.until_deadline(async {
+            let resp = self
+                .send_with_retries(url, &HeaderMap::new(), timeout)
+                .await?;
+            self.read_json(resp).await
+        })
+        .await
+    }
+
+    /// Deserializes the body of `resp` as JSON while it is received, or after buffering
+    /// the full body if responses are buffered.
+    async fn read_json<T: DeserializeOwned + Send + 'static>(&self, resp: Response) -> Result<T> {
+        let status = resp.status().as_u16();
+        let secrets = self.response_secrets(&resp);
+        if self.buffer_responses {
+            let body = self.read_body(resp).await?;
+            tracing::trace!("{}", String::from_utf8_lossy(&body));
+            return parse_json(status, &body, &secrets);
+        }
+
+        let (reader, prefix) = self.body_reader(resp, &secrets)?;
+        let result = tokio::task::spawn_blocking(move || serde_json::from_reader(reader))
+            .await
+            .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+
+        result.map_err(|e| {
+            let prefix = prefix.lock().expect("Body prefix mutex poisoned");
+            read_json_error(status, e, &prefix, &secrets)
+        })
+    }
+
+    /// Deserializes the body of `resp` as a JSON array while it is received, sending each
+    /// element which matches the `predicate` over the returned channel, so the full array
+    /// is never held in memory (unless responses are buffered).
     ///
-    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
-    pub async fn instrument(
+    /// Elements are only read ahead of the receiver up to the channel capacity, and a failure
+    /// to read or deserialize the body is sent as the last message.
+    async fn read_json_filtered<T, P>(
         &self,
-        exchange: Exchange,
-        symbol: &str,
-        start: Option<u64>,
-        end: Option<u64>,
-        ts_init: Option<u64>,
-    ) -> Result<Vec<InstrumentAny>> {
-        let response = self.instrument_info(exchange, symbol).await?;
-        let ts_init = ts_init.map(UnixNanos::from);
+        resp: Response,
+        predicate: P,
+    ) -> Result<mpsc::Receiver<Result<T>>>
+    where
+        T: DeserializeOwned + Send + 'static,
+        P: Fn(&T) -> bool + Send + 'static,
+    {
+        let status = resp.status().as_u16();
+        let secrets = self.response_secrets(&resp);
+        let (reader, prefix): (Box<dyn Read + Send>, _) = if self.buffer_responses {
+            let body = self.read_body(resp).await?;
+            let mut prefix = BodyPrefix::new(&secrets);
+            prefix.extend(&body);
+            (
+                Box::new(std::io::Cursor::new(body)),
+                Arc::new(Mutex::new(prefix)),
+            )
+        } else {
+            let (reader, prefix) = self.body_reader(resp, &secrets)?;
+            (Box::new(reader), prefix)
+        };
 
-        Ok(parse_instrument_any(
-            response,
-            start,
-            end,
-            ts_init,
-            self.normalize_symbols,
-        ))
+        let (tx, rx) = mpsc::channel(FILTERED_READ_AHEAD);
+        tokio::task::spawn_blocking(move || {
+            let mut deserializer = serde_json::Deserializer::from_reader(reader);
+            let visitor = FilteredSeqVisitor {
+                predicate,
+                tx: tx.clone(),
+            };
+            let result = deserializer
+                .deserialize_seq(visitor)
+                .and_then(|()| deserializer.end());
+            if let Err(e) = result {
+                let prefix = prefix.lock().expect("Body prefix mutex poisoned");
+                let error = read_json_error(status, e, &prefix, &secrets);
+                // The receiver may have been dropped, in which case the error is moot
+                let _ = tx.blocking_send(Err(error));
+            }
+        });
+
+        Ok(rx)
+    }
+
+    /// Returns a blocking reader of the body of `resp` as it is received, aborting once it
+    /// exceeds the maximum response size, along with the [`BodyPrefix`] received so far
+    /// (long enough to mask any of the `secrets` crossing the end of the snippet).
+    fn body_reader(
+        &self,
+        resp: Response,
+        secrets: &[String],
+    ) -> Result<(impl Read + Send, Arc<Mutex<BodyPrefix>>)> {
+        self.check_content_length(&resp)?;
+        let limit = self.max_response_bytes;
+        let mut received = 0;
+        let prefix = Arc::new(Mutex::new(BodyPrefix::new(secrets)));
+        let prefix_clone = prefix.clone();
+        let chunks = resp.bytes_stream().map(move |chunk| {
+            let chunk = chunk.map_err(std::io::Error::other)?;
+            received += chunk.len();
+            prefix_clone
+                .lock()
+                .expect("Body prefix mutex poisoned")
+                .extend(&chunk);
+            match limit {
+                Some(limit) if received > limit => {
+                    Err(std::io::Error::other(Error::ResponseTooLarge { limit }))
+                }
+                _ => Ok(chunk),
+            }
+        });
+        let body = StreamReader::new(chunks);
+        Ok((std::io::BufReader::new(SyncIoBridge::new(body)), prefix))
+    }
+
+    /// Reads the raw body of `resp`, aborting once it exceeds the maximum response size.
+    ///
+    /// The body is not validated as UTF-8, which is left to the JSON parser.
+    async fn read_body(&self, resp: Response) -> Result<Vec<u8>> {
+        self.check_content_length(&resp)?;
+
+        let mut body = Vec::new();
+        let mut chunks = resp.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            body.extend_from_slice(&chunk?);
+            if let Some(limit) = self.max_response_bytes.filter(|limit| body.len() > *limit) {
+                return Err(Error::ResponseTooLarge { limit });
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Fails before reading a body whose declared `Content-Length` exceeds the maximum
+    /// response size (compressed bodies are only checked as they are read).
+    fn check_content_length(&self, resp: &Response) -> Result<()> {
+        match (self.max_response_bytes, resp.content_length()) {
+            (Some(limit), Some(len)) if len > limit as u64 => {
+                Err(Error::ResponseTooLarge { limit })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn get_json_with_meta<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(T, ResponseMeta)> {
+        let (body, meta, secrets) = self.get_body(url, timeout).await?;
+        let parsed = parse_json(meta.status, &body, &secrets)?;
+        Ok((parsed, meta))
+    }
+
+    /// Returns the secrets to mask in errors describing the body of `resp`: the API keys
+    /// of the pool, along with the key the request was sent with (which is only known
+    /// once loaded when keys are loaded by a provider).
+    fn response_secrets(&self, resp: &Response) -> Vec<String> {
+        let mut secrets = self.api_keys.keys().to_vec();
+        if let Some(SentApiKey(api_key)) = resp.extensions().get::<SentApiKey>() {
+            if !secrets.contains(api_key) {
+                secrets.push(api_key.clone());
+            }
+        }
+        secrets
+    }
+
+    /// Returns a GET request builder for `url` authenticated with the active API key,
+    /// along with the index and value of that key.
+    ///
+    /// When an API key provider is set, it is called to load the key.
+    fn request_builder(&self, url: &str) -> Result<(usize, String, reqwest::RequestBuilder)> {
+        let (key_index, api_key) = self
+            .api_keys
+            .current()
+            .map_err(|e| Error::ApiKey(e.to_string()))?;
+        let request = self.client.get(url);
+        let request = match &self.auth_header {
+            Some(name) => {
+                let mut value = HeaderValue::from_str(&api_key).map_err(|_| {
+                    Error::ApiKey("API key is not a valid header value".to_string())
+                })?;
+                value.set_sensitive(true);
+                request.header(name, value)
+            }
+            None => request.bearer_auth(&api_key),
+        };
+        let request = match &self.api_version {
+            Some(api_version) => request.header(HEADER_API_VERSION, api_version),
+            None => request,
+        };
+        Ok((key_index, api_key, request))
+    }
+
+    /// Returns the request [`TardisHttpClient::instruments_info`] would send for the given
+    /// `exchange` and `filter`, without sending it, except that a `symbols` filter is not
+    /// represented (as it is resolved with one request per symbol).
+    ///
+    /// The request is built as for sending, with the filter as URL-encoded JSON in the
+    /// query string and the API key as a (sensitive) header in the configured
+    /// [`AuthStyle`]. Client default headers are only applied when a request is sent, so
+    /// are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails to build.
+    pub fn build_instruments_info_request(
+        &self,
+        exchange: &Exchange,
+        filter: Option<&InstrumentFilter>,
+    ) -> Result<reqwest::Request> {
+        let url = self.instruments_info_url(exchange, filter);
+        let (_, _, request) = self.request_builder(&url)?;
+        Ok(request.build()?)
+    }
+
+    fn instruments_info_url(
+        &self,
+        exchange: &Exchange,
+        filter: Option<&InstrumentFilter>,
+    ) -> String {
+        let mut url = format!("{}/instruments/{exchange}", &self.base_url);
+        if let Some(filter) = filter {
+            if let Ok(filter_json) = serde_json::to_string(filter) {
+                url.push_str(&format!("?filter={}", urlencoding::encode(&filter_json)));
+            }
+        }
+        url
+    }
+
+    /// Checks connectivity to the Tardis API and the validity of the API key.
+    ///
+    /// A single lightweight request is made without retries, returning
+    /// [`Error::Unauthorized`] if the API key is rejected.
+    /// The optional `timeout` overrides the client timeout for this request.
+    pub async fn ping(&self, timeout: Option<Duration>) -> Result<()> {
+        let url = format!("{}/api-key-info", &self.base_url);
+        tracing::debug!("Requesting: {url}");
+
+        self.until_deadline(self.send(&url, &HeaderMap::new(), timeout))
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the details of all exchanges supported by Tardis.
+    ///
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/http#exchanges>.
+    pub async fn exchanges(&self, timeout: Option<Duration>) -> Result<Vec<ExchangeDetails>> {
+        let url = format!("{}/exchanges", &self.base_url);
+        tracing::debug!("Requesting: {url}");
+
+        self.get_json(&url, timeout).await
+    }
+
+    /// Returns whether `data_type` is available for the given `exchange`, as a normalized
+    /// data type (such as `incremental_book_L2`) or an exchange-native channel.
+    ///
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::NotFound`] if the exchange is not supported by Tardis.
+    pub async fn exchange_supports(
+        &self,
+        exchange: &Exchange,
+        data_type: &str,
+        timeout: Option<Duration>,
+    ) -> Result<bool> {
+        self.exchanges(timeout)
+            .await?
+            .iter()
+            .find(|details| details.id == *exchange)
+            .map(|details| details.supports(data_type))
+            .ok_or_else(|| Error::NotFound {
+                code: 404,
+                message: format!("Exchange {exchange} not supported by Tardis"),
+            })
+    }
+
+    /// Returns the JSON response of an arbitrary Tardis API endpoint at `path` (relative
+    /// to the base URL, such as `/exchanges/deribit`), with the optional `query` parameters.
+    ///
+    /// The request is authenticated, retried and rate limited as for the wrapped endpoints,
+    /// allowing endpoints without first-class support to be used.
+    pub async fn get_raw(
+        &self,
+        path: &str,
+        query: Option<&[(String, String)]>,
+    ) -> Result<serde_json::Value> {
+        let mut url = format!("{}{path}", &self.base_url);
+        if let Some(query) = query.filter(|query| !query.is_empty()) {
+            let query: Vec<String> = query
+                .iter()
+                .map(|(key, value)| {
+                    format!(
+                        "{}={}",
+                        urlencoding::encode(key),
+                        urlencoding::encode(value)
+                    )
+                })
+                .collect();
+            url.push(if url.contains('?') { '&' } else { '?' });
+            url.push_str(&query.join("&"));
+        }
+        tracing::debug!("Requesting: {url}");
+
+        self.get_json(&url, None).await
+    }
+
+    /// Returns all Tardis instrument definitions for the given `exchange`.
+    ///
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn instruments_info(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentInfo>> {
+        let span = request_span("instruments_info", &exchange);
+        async {
+            let cache_key = (exchange.clone(), filter.cloned().unwrap_or_default());
+            if let Some(cached) = self
+                .state
+                .instruments_cache
+                .as_ref()
+                .and_then(|cache| cache.get(&cache_key))
+            {
+                tracing::debug!("Using cached instruments for {exchange}");
+                return Ok(cached);
+            }
+
+            let instruments = match filter.and_then(InstrumentFilter::requested_symbols) {
+                Some(symbols) => {
+                    let instrument_types =
+                        filter.and_then(|filter| filter.instrument_type.as_deref());
+                    self.symbols_info(&exchange, symbols, instrument_types, timeout)
+                        .await?
+                }
+                None => {
+                    let url = self.instruments_info_url(&exchange, filter);
+                    tracing::debug!("Requesting: {url}");
+
+                    match (&self.disk_cache, &self.state.instruments_validators) {
+                        (Some(disk_cache), _) => {
+                            let key =
+                                DiskCache::key(&exchange, &cache_key.1, Utc::now().date_naive());
+                            self.get_json_disk_cached(disk_cache, &key, &url, timeout)
+                                .await?
+                        }
+                        (None, Some(validators)) => {
+                            self.get_json_conditional(validators, &cache_key, &url, timeout)
+                                .await?
+                        }
+                        (None, None) => self.get_json(&url, timeout).await?,
+                    }
+                }
+            };
+
+            if let (true, Some(filter)) = (self.validate_filter, filter) {
+                warn_filter_mismatches(&exchange, filter, &instruments);
+            }
+
+            if let Some(cache) = &self.state.instruments_cache {
+                cache.insert(cache_key, instruments.clone());
+            }
+
+            Ok(instruments)
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Preloads the instruments cache with the Tardis instrument definitions for each of the
+    /// given `exchanges` matching the `filter`, such as during startup so the first
+    /// [`Self::instruments_info`] call for each exchange is served from the cache.
+    ///
+    /// Exchanges are requested concurrently, returning once all requests complete. Each
+    /// failure is logged, and the first is returned once the other exchanges are loaded.
+    /// Nothing is requested unless caching is enabled with [`Self::with_cache`].
+    /// The optional `timeout` overrides the client timeout for each request.
+    pub async fn warm_cache(
+        &self,
+        exchanges: &[Exchange],
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        if self.state.instruments_cache.is_none() {
+            tracing::warn!("Instruments cache not enabled, skipping warm up");
+            return Ok(());
+        }
+
+        let results = future::join_all(exchanges.iter().map(|exchange| async move {
+            let result = self
+                .instruments_info(exchange.clone(), filter, timeout)
+                .await;
+            match &result {
+                Ok(instruments) => {
+                    tracing::info!("Cached {} instruments for {exchange}", instruments.len())
+                }
+                Err(e) => tracing::error!("Error caching instruments for {exchange}: {e}"),
+            }
+            result
+        }))
+        .await;
+
+        results.into_iter().try_for_each(|result| result.map(drop))
+    }
+
+    /// Returns the instruments stored under `key` in the `disk_cache`, otherwise requests
+    /// `url` and stores the response body.
+    ///
+    /// Snapshots which fail to read or parse are logged and requested again.
+    async fn get_json_disk_cached(
+        &self,
+        disk_cache: &DiskCache,
+        key: &str,
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentInfo>> {
+        let path = disk_cache.path(key);
+        match disk_cache.get(key) {
+            Ok(Some(body)) => {
+                match parse_json(StatusCode::OK.as_u16(), &body, self.api_keys.keys()) {
+                    Ok(instruments) => {
+                        tracing::debug!("Using instruments from disk cache {}", path.display());
+                        return Ok(instruments);
+                    }
+                    Err(e) => tracing::warn!("Invalid disk cache snapshot {}: {e}", path.display()),
+                }
+            }
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Error reading disk cache snapshot {}: {e}", path.display()),
+        }
+
+        tracing::debug!("Requesting: {url}");
+        let (body, meta, secrets) = self.get_body(url, timeout).await?;
+        let instruments = parse_json(meta.status, &body, &secrets)?;
+
+        disk_cache.insert(key, &body)?;
+        tracing::debug!("Stored instruments in disk cache {}", path.display());
+
+        Ok(instruments)
+    }
+
+    /// Requests `url` with the conditional headers for the response stored under `key`,
+    /// returning the stored response if the API responds with `304 Not Modified`.
+    async fn get_json_conditional(
+        &self,
+        validators: &InstrumentsInfoValidators,
+        key: &(Exchange, InstrumentFilter),
+        url: &str,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentInfo>> {
+        let headers = validators.conditional_headers(key);
+        let resp = self
+            .until_deadline(self.send_with_retries(url, &headers, timeout))
+            .await?;
+
+        if resp.status() == StatusCode::NOT_MODIFIED {
+            return match validators.get(key) {
+                Some(instruments) => {
+                    tracing::debug!("Instruments for {} not modified", key.0);
+                    Ok(instruments)
+                }
+                None => Err(Error::ApiError {
+                    status: StatusCode::NOT_MODIFIED.as_u16(),
+                    code: u64::from(StatusCode::NOT_MODIFIED.as_u16()),
+                    message: "Not modified without a stored response".to_string(),
+                }),
+            };
+        }
+
+        let response_headers = resp.headers().clone();
+        let instruments: Vec<InstrumentInfo> = self.until_deadline(self.read_json(resp)).await?;
+        validators.insert(key.clone(), &response_headers, instruments.clone());
+
+        Ok(instruments)
+    }
+
+    /// Returns the Tardis instrument definitions for each of the given `symbols`.
+    ///
+    /// The instruments metadata API has no symbol filter, so each symbol is requested
+    /// concurrently from the single instrument endpoint, and any `instrument_types`
+    /// filter is applied client-side.
+    async fn symbols_info(
+        &self,
+        exchange: &Exchange,
+        symbols: &[String],
+        instrument_types: Option<&[String]>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentInfo>> {
+        let infos = future::try_join_all(
+            symbols
+                .iter()
+                .map(|symbol| self.instrument_info(exchange.clone(), symbol, timeout)),
+        )
+        .await?;
+
+        Ok(infos
+            .into_iter()
+            .filter(|info| {
+                instrument_types
+                    .is_none_or(|types| types.contains(&info.instrument_type.to_string()))
+            })
+            .collect())
+    }
+
+    /// Returns all Tardis instrument definitions for the given `exchange`, along with
+    /// metadata about the response for diagnostics.
+    ///
+    /// Unlike [`Self::instruments_info`], a single request is always made to the instruments
+    /// endpoint: the response is never served from the cache, and the `symbols` filter is
+    /// not applied. The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn instruments_info_with_meta(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<(Vec<InstrumentInfo>, ResponseMeta)> {
+        let url = self.instruments_info_url(&exchange, filter);
+        tracing::debug!("Requesting: {url}");
+
+        self.get_json_with_meta(&url, timeout).await
+    }
+
+    /// Returns the number of Tardis instrument definitions for the given `exchange` which
+    /// match the `filter`, such as for dashboards which only need the count.
+    ///
+    /// Tardis has no count endpoint, so the instruments metadata is requested as for
+    /// [`Self::instruments_info`], but each definition is only counted while the response
+    /// is deserialized, without allocating it. A cached [`Self::instruments_info`] response
+    /// is counted without a request, while a `symbols` filter is resolved with
+    /// [`Self::instruments_info`] (one request per symbol).
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn instruments_count(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<usize> {
+        let cache_key = (exchange.clone(), filter.cloned().unwrap_or_default());
+        if let Some(cached) = self
+            .state
+            .instruments_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&cache_key))
+        {
+            tracing::debug!("Counting cached instruments for {exchange}");
+            return Ok(cached.len());
+        }
+
+        if filter
+            .and_then(InstrumentFilter::requested_symbols)
+            .is_some()
+        {
+            let instruments = self.instruments_info(exchange, filter, timeout).await?;
+            return Ok(instruments.len());
+        }
+
+        let url = self.instruments_info_url(&exchange, filter);
+        tracing::debug!("Requesting: {url}");
+
+        let ArrayLen(count) = self.get_json(&url, timeout).await?;
+        Ok(count)
+    }
+
+    /// Returns the Tardis symbols of the instruments for the given `exchange` which match
+    /// the `filter`, such as to validate user input or for symbol autocompletion.
+    ///
+    /// The instruments metadata is requested as for [`Self::instruments_info`], but only the
+    /// symbol of each definition is deserialized, skipping the other fields without
+    /// allocating them. A cached [`Self::instruments_info`] response is used without a
+    /// request, while a `symbols` filter is resolved with [`Self::instruments_info`] (one
+    /// request per symbol).
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn symbols(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<String>> {
+        let cache_key = (exchange.clone(), filter.cloned().unwrap_or_default());
+        let cached = self
+            .state
+            .instruments_cache
+            .as_ref()
+            .and_then(|cache| cache.get(&cache_key));
+        if cached.is_some()
+            || filter
+                .and_then(InstrumentFilter::requested_symbols)
+                .is_some()
+        {
+            let instruments = match cached {
+                Some(cached) => cached,
+                None => self.instruments_info(exchange, filter, timeout).await?,
+            };
+            return Ok(instruments.iter().map(|info| info.id.to_string()).collect());
+        }
+
+        let url = self.instruments_info_url(&exchange, filter);
+        tracing::debug!("Requesting: {url}");
+
+        let symbols: Vec<InstrumentSymbol> = self.get_json(&url, timeout).await?;
+        Ok(symbols
+            .into_iter()
+            .map(|symbol| symbol.id.to_string())
+            .collect())
+    }
+
+    /// Saves the raw Tardis instrument definitions for the given `exchange` to `path`.
+    ///
+    /// The response body is written unmodified, so the file can later be loaded with
+    /// [`Self::instruments_from_file`] for offline use.
+    /// The optional `timeout` overrides the client timeout for this request.
+    pub async fn save_instruments_info(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        path: &Path,
+        timeout: Option<Duration>,
+    ) -> Result<()> {
+        let url = self.instruments_info_url(&exchange, filter);
+        tracing::debug!("Requesting: {url}");
+
+        let (body, meta, secrets) = self.get_body(&url, timeout).await?;
+
+        // Validate the body before writing so a corrupt snapshot is never saved
+        parse_json::<Vec<InstrumentInfo>>(meta.status, &body, &secrets)?;
+
+        fs::write(path, body)?;
+        tracing::info!("Saved instruments for {exchange} to {}", path.display());
+
+        Ok(())
+    }
+
+    /// Writes a snapshot of the instrument universe of the given `exchanges` to a Parquet
+    /// file at `path`, returning the number of instruments written.
+    ///
+    /// Each exchange is requested concurrently, and the file has one row per instrument
+    /// with the columns of [`instruments_schema`](super::export::instruments_schema). Symbols
+    /// are normalized according to the client setting. The optional `timeout` overrides the
+    /// client timeout for each request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a request fails, in which case no file is written, or if the
+    /// file fails to write.
+    pub async fn instruments_to_parquet(
+        &self,
+        exchanges: &[Exchange],
+        path: &Path,
+        timeout: Option<Duration>,
+    ) -> Result<usize> {
+        let infos: Vec<InstrumentInfo> = future::try_join_all(
+            exchanges
+                .iter()
+                .map(|exchange| self.instruments_info(exchange.clone(), None, timeout)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+
+        let batch = instruments_to_record_batch(&infos, self.normalizer(None))
+            .map_err(|e| Error::Parquet(e.to_string()))?;
+        let num_rows = batch.num_rows();
+        write_batch_to_parquet(batch, path, None).map_err(|e| Error::Parquet(e.to_string()))?;
+        tracing::info!("Saved {num_rows} instruments to {}", path.display());
+
+        Ok(num_rows)
+    }
+
+    /// Returns a stream of Tardis instrument definitions for the given `exchange`.
+    ///
+    /// The instruments metadata API does not support offset/limit pagination, so results
+    /// are paged by instrument type instead: one request is made per type (restricted to
+    /// the types in `filter`, if any), and each page is only requested once the previous
+    /// page has been consumed. The stream ends after the first error.
+    ///
+    /// If the optional `cancellation` token is cancelled, any in-flight page request is
+    /// abandoned and the stream ends with an [`Error::Cancelled`].
+    /// The optional `timeout` overrides the client timeout for each page request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub fn instruments_info_stream(
+        &self,
+        exchange: Exchange,
+        filter: Option<&InstrumentFilter>,
+        cancellation: Option<CancellationToken>,
+        timeout: Option<Duration>,
+    ) -> impl Stream<Item = Result<InstrumentInfo>> + '_ {
+        let filter = filter.cloned().unwrap_or_default();
+        let instrument_types = filter
+            .instrument_type
+            .clone()
+            .filter(|instrument_types| !instrument_types.is_empty())
+            .unwrap_or_else(|| {
+                InstrumentType::iter()
+                    .map(|instrument_type| instrument_type.to_string())
+                    .collect()
+            });
+
+        stream! {
+            for instrument_type in instrument_types {
+                let page_filter = InstrumentFilter {
+                    instrument_type: Some(vec![instrument_type]),
+                    ..filter.clone()
+                };
+
+                let page = self.instruments_info(exchange.clone(), Some(&page_filter), timeout);
+                match with_cancellation(cancellation.as_ref(), page).await {
+                    Ok(page) => {
+                        for info in page {
+                            yield Ok(info);
+                        }
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the Tardis instrument definition for a given `exchange` and `symbol`.
+    ///
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api#single-instrument-info-endpoint>.
+    pub async fn instrument_info(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+        timeout: Option<Duration>,
+    ) -> Result<InstrumentInfo> {
+        let url = format!("{}/instruments/{exchange}/{symbol}", &self.base_url);
+        let span = request_span("instrument_info", &exchange);
+
+        async {
+            let Some(cache) = &self.state.instrument_cache else {
+                tracing::debug!("Requesting {url}");
+                return self.get_json(&url, timeout).await;
+            };
+
+            let cache_key = (exchange.clone(), symbol.to_string());
+            match cache.get(&cache_key) {
+                Some(Ok(info)) => {
+                    tracing::debug!("Using cached instrument {symbol} for {exchange}");
+                    return Ok(info);
+                }
+                Some(Err((code, message))) => {
+                    tracing::debug!("Using cached not found instrument {symbol} for {exchange}");
+                    return Err(Error::NotFound { code, message });
+                }
+                None => {}
+            }
+
+            tracing::debug!("Requesting {url}");
+            match self.get_json::<InstrumentInfo>(&url, timeout).await {
+                Ok(info) => {
+                    cache.insert(cache_key, info.clone());
+                    Ok(info)
+                }
+                Err(Error::NotFound { code, message }) => {
+                    cache.insert_not_found(cache_key, (code, message.clone()));
+                    Err(Error::NotFound { code, message })
+                }
+                Err(e) => Err(e),
+            }
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// Returns the range of data availability for a given `exchange` and `symbol`, as the
+    /// `availableSince` and `availableTo` UNIX timestamps (nanoseconds).
+    ///
+    /// The end is `None` while data is still being collected for the instrument.
+    /// The optional `timeout` overrides the client timeout for this request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api#single-instrument-info-endpoint>.
+    pub async fn instrument_availability(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+        timeout: Option<Duration>,
+    ) -> Result<(UnixNanos, Option<UnixNanos>)> {
+        let info = self.instrument_info(exchange, symbol, timeout).await?;
+        let available_since = parse_datetime_to_unix_nanos(Some(info.available_since));
+        let available_to = info
+            .available_to
+            .map(|available_to| parse_datetime_to_unix_nanos(Some(available_to)));
+
+        Ok((available_since, available_to))
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`.
+    ///
+    /// The `options` can keep only instruments of given Nautilus instrument classes or for
+    /// which a predicate returns true (such as a tick size or multiplier range), both
+    /// applied after parsing, and override the client symbol normalization and timeout.
+    ///
+    /// If enabled with [`TardisHttpClientBuilder::dedup_instruments`], instruments from
+    /// different exchange symbols which map to the same instrument ID are collapsed.
+    ///
+    /// Definitions which fail to parse are skipped with a warning, see
+    /// [`Self::instruments_with_skipped`] to also return them.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn instruments(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        options: &InstrumentOptions,
+    ) -> Result<Vec<InstrumentAny>> {
+        let (instruments, _) = self
+            .instruments_with_skipped(exchange, start, end, ts_init, filter, options)
+            .await?;
+        Ok(instruments)
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`, flattened to
+    /// [`InstrumentRecord`]s of plain scalar fields for columnar analysis (such as with
+    /// Polars).
+    ///
+    /// See [`Self::instruments`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instruments_records(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentRecord>> {
+        let options = InstrumentOptions {
+            normalize_symbols,
+            timeout,
+            ..Default::default()
+        };
+        let instruments = self
+            .instruments(exchange, start, end, ts_init, filter, &options)
+            .await?;
+        Ok(instruments.iter().map(InstrumentRecord::from).collect())
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`, failing with
+    /// [`Error::NoInstrumentsMatched`] if there are none.
+    ///
+    /// Use this where an empty result means a misconfigured filter (rather than an empty
+    /// venue), so the misconfiguration fails loudly at fetch time.
+    ///
+    /// See [`Self::instruments`].
+    pub async fn instruments_required(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        options: &InstrumentOptions,
+    ) -> Result<Vec<InstrumentAny>> {
+        let instruments = self
+            .instruments(exchange.clone(), start, end, ts_init, filter, options)
+            .await?;
+
+        if instruments.is_empty() {
+            tracing::error!("No instruments matched for {exchange} with filter {filter:?}");
+            return Err(Error::NoInstrumentsMatched { exchange });
+        }
+        Ok(instruments)
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`, along with
+    /// each Tardis instrument definition which failed to parse and the reason.
+    ///
+    /// See [`Self::instruments`].
+    pub async fn instruments_with_skipped(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        options: &InstrumentOptions,
+    ) -> Result<(Vec<InstrumentAny>, Vec<SkippedInstrument>)> {
+        let response = self
+            .instruments_info(exchange, filter, options.timeout)
+            .await?;
+        let ts_init = ts_init.map(UnixNanos::from);
+        let (mut instruments, skipped) = parse_instrument_infos(
+            response,
+            start,
+            end,
+            ts_init,
+            self.normalizer(options.normalize_symbols),
+        );
+        instruments.retain(|instrument| {
+            matches_parsed_filter(filter, instrument) && options.matches(instrument)
+        });
+
+        if self.dedup_instruments {
+            instruments = dedup_instruments(instruments);
+        }
+        Ok((instruments, skipped))
+    }
+
+    /// Returns each Tardis instrument definition for the given `exchange` alongside the
+    /// Nautilus instrument definitions parsed from it, from a single request.
+    ///
+    /// This keeps the raw metadata (such as for audit) consistent with the parsed
+    /// instruments, with one entry per definition returned by [`Self::instruments_info`].
+    /// Definitions which fail to parse are kept with no instruments (and logged as a
+    /// warning), and instruments are not deduplicated across definitions.
+    ///
+    /// See [`Self::instruments`] for the other parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instruments_with_info(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<(InstrumentInfo, Vec<InstrumentAny>)>> {
+        let response = self.instruments_info(exchange, filter, timeout).await?;
+        let ts_init = ts_init.map(UnixNanos::from);
+        let normalizer = self.normalizer(normalize_symbols);
+
+        Ok(response
+            .into_iter()
+            .map(|info| {
+                let instruments = match parse_instrument_any_with_normalizer(
+                    &info, start, end, ts_init, normalizer,
+                ) {
+                    Ok(mut instruments) => {
+                        instruments.retain(|instrument| matches_parsed_filter(filter, instrument));
+                        instruments
+                    }
+                    Err(e) => {
+                        tracing::warn!("Skipping instrument {}: {e}", info.id);
+                        Vec::new()
+                    }
+                };
+                (info, instruments)
+            })
+            .collect())
+    }
+
+    /// Returns the Nautilus instrument definitions for the given `exchange` whose metadata
+    /// changed after `since`, for incremental syncs of the instrument universe.
+    ///
+    /// Tardis has no endpoint for changes, so all instrument metadata is requested and
+    /// filtered by [`InstrumentInfo::last_changed`] (covering listings, specification
+    /// changes and delistings). The definitions in effect from `since` are returned, which
+    /// include the definition in effect at `since` for instruments with a changed
+    /// specification. See [`Self::instruments`] for the other parameters.
+    pub async fn instruments_changed_since(
+        &self,
+        exchange: Exchange,
+        since: UnixNanos,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentAny>> {
+        let mut infos = self.instruments_info(exchange, filter, timeout).await?;
+        infos.retain(|info| parse_datetime_to_unix_nanos(Some(info.last_changed())) > since);
+
+        let (mut instruments, _) = parse_instrument_infos(
+            infos,
+            Some(since.as_u64()),
+            None,
+            ts_init.map(UnixNanos::from),
+            self.normalizer(normalize_symbols),
+        );
+        instruments.retain(|instrument| matches_parsed_filter(filter, instrument));
+
+        if self.dedup_instruments {
+            instruments = dedup_instruments(instruments);
+        }
+        Ok(instruments)
+    }
+
+    /// Returns a stream of Nautilus instrument definitions for the given `exchange`.
+    ///
+    /// The instrument metadata is requested once when the stream is first polled, then each
+    /// Tardis instrument definition is only parsed as the stream is consumed, avoiding
+    /// buffering every instrument (and change) up front. A failed request is yielded as a
+    /// single error, after which the stream ends, while definitions which fail to parse are
+    /// skipped with a warning.
+    ///
+    /// The optional `normalize_symbols` overrides the client setting, and the optional
+    /// `timeout` overrides the client timeout for the request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    #[allow(clippy::too_many_arguments)]
+    pub fn instruments_stream(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> impl Stream<Item = Result<InstrumentAny>> + '_ {
+        let filter = filter.cloned();
+        let ts_init = ts_init.map(UnixNanos::from);
+        let normalizer = self.normalizer(normalize_symbols);
+
+        stream! {
+            match self.instruments_info(exchange, filter.as_ref(), timeout).await {
+                Ok(response) => {
+                    for info in response {
+                        let instruments =
+                            match parse_instrument_any_with_normalizer(&info, start, end, ts_init, normalizer) {
+                                Ok(instruments) => instruments,
+                                Err(e) => {
+                                    tracing::warn!("Skipping instrument {}: {e}", info.id);
+                                    continue;
+                                }
+                            };
+                        for instrument in instruments {
+                            if matches_parsed_filter(filter.as_ref(), &instrument) {
+                                yield Ok(instrument);
+                            }
+                        }
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+        }
+    }
+
+    /// Returns a stream of Nautilus instrument definitions for the given `exchange`, parsed
+    /// from only the Tardis instrument definitions which match the `predicate`.
+    ///
+    /// Unlike [`Self::instruments_stream`], the response is deserialized one definition at a
+    /// time as it is received, and definitions which do not match are dropped immediately,
+    /// so the full list of instruments is never held in memory (unless responses are
+    /// buffered). This suits memory-constrained consumers which only want a few instruments
+    /// of an exchange with a large universe. A failed request or malformed response is
+    /// yielded as a single error, after which the stream ends, while matching definitions
+    /// which fail to parse are skipped with a warning.
+    ///
+    /// The instruments cache is bypassed, as the full list is never collected.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub fn instruments_filtered_stream<P>(
+        &self,
+        exchange: Exchange,
+        predicate: P,
+    ) -> impl Stream<Item = Result<InstrumentAny>> + '_
+    where
+        P: Fn(&InstrumentInfo) -> bool + Send + 'static,
+    {
+        let url = self.instruments_info_url(&exchange, None);
+        let normalizer = self.normalizer(None);
+
+        stream! {
+            tracing::debug!("Requesting: {url}");
+            let infos = match self
+                .until_deadline(self.send_with_retries(&url, &HeaderMap::new(), None))
+                .await
+            {
+                Ok(resp) => self.read_json_filtered(resp, predicate).await,
+                Err(e) => Err(e),
+            };
+
+            match infos {
+                Ok(mut infos) => {
+                    while let Some(info) = infos.recv().await {
+                        let info = match info {
+                            Ok(info) => info,
+                            Err(e) => {
+                                yield Err(e);
+                                break;
+                            }
+                        };
+                        match parse_instrument_any_with_normalizer(&info, None, None, None, normalizer) {
+                            Ok(instruments) => {
+                                for instrument in instruments {
+                                    yield Ok(instrument);
+                                }
+                            }
+                            Err(e) => tracing::warn!("Skipping instrument {}: {e}", info.id),
+                        }
+                    }
+                }
+                Err(e) => yield Err(e),
+            }
+        }
+    }
+
+    /// Parses the Nautilus instrument definitions for the given `exchange` in batches of
+    /// `batch_size`, passing each batch to the `callback` before parsing the next, and
+    /// returns the total number of instruments parsed.
+    ///
+    /// The response is deserialized as it is received (see
+    /// [`Self::instruments_filtered_stream`]), and each batch is dropped once the callback
+    /// returns, so peak memory is bounded by `batch_size` rather than the full instrument
+    /// universe. This suits ETL jobs which write each batch to storage immediately. The
+    /// final batch may be smaller, and a `batch_size` of zero is treated as one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the response is malformed, after the
+    /// batches parsed up to that point have been passed to the `callback`.
+    pub async fn instruments_batched<F>(
+        &self,
+        exchange: Exchange,
+        batch_size: usize,
+        mut callback: F,
+    ) -> Result<usize>
+    where
+        F: FnMut(Vec<InstrumentAny>),
+    {
+        let batch_size = batch_size.max(1);
+        let instruments = self.instruments_filtered_stream(exchange, |_| true);
+        tokio::pin!(instruments);
+
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut count = 0;
+        while let Some(instrument) = instruments.next().await {
+            let instrument = match instrument {
+                Ok(instrument) => instrument,
+                Err(e) => {
+                    if !batch.is_empty() {
+                        callback(batch);
+                    }
+                    return Err(e);
+                }
+            };
+
+            batch.push(instrument);
+            count += 1;
+            if batch.len() == batch_size {
+                callback(std::mem::replace(
+                    &mut batch,
+                    Vec::with_capacity(batch_size),
+                ));
+            }
+        }
+        if !batch.is_empty() {
+            callback(batch);
+        }
+
+        Ok(count)
+    }
+
+    /// Returns a stream of the raw exchange messages of the `request`, replayed from the
+    /// historical data of the Tardis HTTP API.
+    ///
+    /// Each minute of the range is requested in turn as the stream is consumed, and its
+    /// messages are parsed as the response is received, so memory is bounded by a single
+    /// message. Empty lines, which mark disconnects of the exchange connection used to
+    /// collect the data, are skipped. A failed request or malformed line is yielded as a
+    /// single error, after which the stream ends.
+    ///
+    /// See <https://docs.tardis.dev/api/http#data-feeds-exchange>.
+    pub fn replay(&self, request: ReplayRequest) -> impl Stream<Item = Result<ReplayMessage>> + '_ {
+        stream! {
+            'minutes: for (date, offset) in request.minutes() {
+                let url = self.replay_url(&request, date, offset);
+                tracing::debug!("Requesting: {url}");
+
+                let resp = match self
+                    .until_deadline(self.send_with_retries(&url, &HeaderMap::new(), None))
+                    .await
+                {
+                    Ok(resp) => resp,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                };
+
+                let mut lines = StreamReader::new(resp.bytes_stream().map_err(std::io::Error::other)).lines();
+                loop {
+                    match lines.next_line().await {
+                        Ok(Some(line)) => match parse_replay_line(&line) {
+                            Ok(Some(message)) if request.contains(message.local_timestamp) => {
+                                yield Ok(message);
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                yield Err(e);
+                                break 'minutes;
+                            }
+                        },
+                        Ok(None) => break,
+                        Err(e) => {
+                            yield Err(unwrap_io_error(e));
+                            break 'minutes;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn replay_url(&self, request: &ReplayRequest, date: NaiveDate, offset: u32) -> String {
+        let mut url = format!(
+            "{}/data-feeds/{}?from={date}&offset={offset}",
+            &self.base_url, request.exchange
+        );
+        if !request.filters.is_empty() {
+            if let Ok(filters_json) = serde_json::to_string(&request.filters) {
+                url.push_str(&format!("&filters={}", urlencoding::encode(&filters_json)));
+            }
+        }
+        url
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange` from the Tardis
+    /// instrument definitions saved at `path`, without making any requests.
+    ///
+    /// The file must contain the JSON body returned by [`Self::instruments_info`], as
+    /// written by [`Self::save_instruments_info`]. Definitions for other exchanges, or which
+    /// fail to parse, are skipped.
+    pub fn instruments_from_file(
+        &self,
+        path: &Path,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+    ) -> Result<Vec<InstrumentAny>> {
+        let body = fs::read_to_string(path)?;
+        let response: Vec<InstrumentInfo> = serde_json::from_str(&body)?;
+        let response = response
+            .into_iter()
+            .filter(|info| info.exchange == exchange)
+            .collect();
+        let ts_init = ts_init.map(UnixNanos::from);
+
+        let (instruments, _) =
+            parse_instrument_infos(response, start, end, ts_init, self.normalizer(None));
+        Ok(instruments)
+    }
+
+    /// Returns all Nautilus instrument definitions for each of the given `exchanges`.
+    ///
+    /// Requests are made concurrently, with at most `concurrency` requests in flight.
+    /// A failure for one exchange does not abort the others, so each exchange maps
+    /// to its own result.
+    ///
+    /// If the optional `cancellation` token is cancelled, in-flight and pending requests
+    /// are abandoned and their exchanges map to an [`Error::Cancelled`].
+    /// The optional `timeout` overrides the client timeout for each request.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instruments_multi(
+        &self,
+        exchanges: &[Exchange],
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        concurrency: usize,
+        cancellation: Option<CancellationToken>,
+        timeout: Option<Duration>,
+    ) -> HashMap<Exchange, Result<Vec<InstrumentAny>>> {
+        let cancellation = cancellation.as_ref();
+        let options = &InstrumentOptions {
+            timeout,
+            ..Default::default()
+        };
+        stream::iter(exchanges.iter().cloned())
+            .map(|exchange| async move {
+                let instruments =
+                    self.instruments(exchange.clone(), start, end, ts_init, filter, options);
+                let result = with_cancellation(cancellation, instruments).await;
+                if let Err(e) = &result {
+                    tracing::error!("Error fetching instruments for {exchange}: {e}");
+                }
+                (exchange, result)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Spawns a task which fetches the Nautilus instrument definitions for each of the
+    /// given `exchanges` immediately and then every `interval`, publishing the latest over
+    /// the returned watch channel (such as to pick up new listings and delistings).
+    ///
+    /// The channel starts empty, and an exchange keeps its previous instruments when a
+    /// refresh fails for it. The task ends once every receiver is dropped, or when aborted
+    /// through the returned handle. Responses cached with [`Self::with_cache`] are served
+    /// until they expire, so the cache TTL should be shorter than the `interval`.
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn spawn_auto_refresh(
+        &self,
+        exchanges: &[Exchange],
+        interval: Duration,
+    ) -> (
+        JoinHandle<()>,
+        watch::Receiver<HashMap<Exchange, Vec<InstrumentAny>>>,
+    ) {
+        let client = self.clone();
+        let exchanges = exchanges.to_vec();
+        let (tx, rx) = watch::channel(HashMap::new());
+
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    () = tx.closed() => break,
+                }
+
+                let results = client
+                    .instruments_multi(
+                        &exchanges,
+                        None,
+                        None,
+                        None,
+                        None,
+                        exchanges.len(),
+                        None,
+                        None,
+                    )
+                    .await;
+                tx.send_modify(|instruments| {
+                    for (exchange, result) in results {
+                        match result {
+                            Ok(latest) => {
+                                tracing::debug!(
+                                    "Refreshed {} instruments for {exchange}",
+                                    latest.len()
+                                );
+                                instruments.insert(exchange, latest);
+                            }
+                            Err(e) => tracing::warn!(
+                                "Keeping previous instruments for {exchange} after failed refresh: {e}"
+                            ),
+                        }
+                    }
+                });
+            }
+            tracing::debug!("Instrument auto refresh stopped");
+        });
+
+        (handle, rx)
+    }
+
+    /// Returns a Nautilus instrument definition for the given `exchange` and `symbol`.
+    ///
+    /// See [`Self::instruments`] for the `options`.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
+    pub async fn instrument(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        options: &InstrumentOptions,
+    ) -> Result<Vec<InstrumentAny>> {
+        let response = self
+            .instrument_info(exchange, symbol, options.timeout)
+            .await?;
+        let ts_init = ts_init.map(UnixNanos::from);
+        let normalizer = self.normalizer(options.normalize_symbols);
+
+        let mut instruments =
+            parse_instrument_any_with_normalizer(&response, start, end, ts_init, normalizer)
+                .map_err(|e| Error::InstrumentParse {
+                    symbol: response.id.to_string(),
+                    message: e.to_string(),
+                })?;
+        instruments.retain(|instrument| options.matches(instrument));
+        Ok(instruments)
+    }
+
+    /// Returns the Nautilus instrument definitions for the given Nautilus `instrument_id`.
+    ///
+    /// The venue is mapped back to the Tardis exchanges sharing it, and the symbol to the
+    /// raw Tardis symbol by removing any suffix appended by the [`DefaultSymbolNormalizer`]
+    /// (symbols are used as is unless normalized). As a venue may cover several Tardis
+    /// exchanges (such as `BINANCE` for spot and futures), each exchange is requested in
+    /// turn until one returns the instrument with the given ID, which may take several
+    /// requests. Returns an [`Error::NotFound`] if no exchange has the instrument.
+    ///
+    /// The optional `timeout` overrides the client timeout for each request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api#single-instrument-info-endpoint>.
+    pub async fn instrument_by_id(
+        &self,
+        instrument_id: &InstrumentId,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentAny>> {
+        let exchanges = Exchange::from_venue_str(instrument_id.venue.as_str());
+
+        let options = InstrumentOptions {
+            timeout,
+            ..Default::default()
+        };
+        let symbol = instrument_id.symbol.as_str();
+        for exchange in exchanges {
+            let raw_symbol = if self.normalize_symbols {
+                denormalize_symbol_str(symbol, &exchange)
+            } else {
+                symbol
+            };
+
+            let instruments = match self
+                .instrument(exchange.clone(), raw_symbol, start, end, ts_init, &options)
+                .await
+            {
+                Ok(instruments) => instruments,
+                Err(Error::NotFound { .. }) => continue,
+                Err(e) => return Err(e),
+            };
+
+            if instruments
+                .iter()
+                .any(|instrument| instrument.id() == *instrument_id)
+            {
+                return Ok(instruments
+                    .into_iter()
+                    .filter(|instrument| instrument.id() == *instrument_id)
+                    .collect());
+            }
+            tracing::debug!(
+                "Instrument {raw_symbol} for {exchange} does not match {instrument_id}"
+            );
+        }
+
+        Err(Error::NotFound {
+            code: 404,
+            message: format!("Instrument {instrument_id} not found"),
+        })
+    }
+
+    /// Returns the Nautilus instrument definitions for each of the given `symbols` of the
+    /// `exchange`, in the order of the symbols.
+    ///
+    /// Symbols are requested concurrently from the single instrument endpoint, with at most
+    /// `concurrency` requests in flight (subject to any client rate limit). The first
+    /// failure aborts the remaining requests and is returned as an [`Error::Symbol`] naming
+    /// the symbol, while each failure is logged.
+    ///
+    /// The optional `normalize_symbols` overrides the client setting for these requests,
+    /// and the optional `timeout` overrides the client timeout for each request.
+    ///
+    /// See <https://docs.tardis.dev/api/instruments-metadata-api#single-instrument-info-endpoint>.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instruments_by_symbols(
+        &self,
+        exchange: Exchange,
+        symbols: &[String],
+        concurrency: usize,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Result<Vec<InstrumentAny>> {
+        let exchange = &exchange;
+        let options = &InstrumentOptions {
+            normalize_symbols,
+            timeout,
+            ..Default::default()
+        };
+        let mut results: Vec<(usize, Vec<InstrumentAny>)> =
+            stream::iter(symbols.iter().enumerate())
+                .map(|(index, symbol)| async move {
+                    self.instrument(exchange.clone(), symbol, start, end, ts_init, options)
+                        .await
+                        .map(|instruments| (index, instruments))
+                        .map_err(|e| {
+                            tracing::error!(
+                                "Error fetching instrument {symbol} for {exchange}: {e}"
+                            );
+                            Error::Symbol {
+                                symbol: symbol.clone(),
+                                source: Box::new(e),
+                            }
+                        })
+                })
+                .buffer_unordered(concurrency.max(1))
+                .try_collect()
+                .await?;
+
+        results.sort_unstable_by_key(|(index, _)| *index);
+        Ok(results
+            .into_iter()
+            .flat_map(|(_, instruments)| instruments)
+            .collect())
+    }
+
+    /// Returns all Nautilus instrument definitions for the given `exchange`, grouped by
+    /// base currency.
+    ///
+    /// Options are grouped by their underlying currency, and any instrument with neither
+    /// is skipped with a warning. See [`Self::instruments`] for the other parameters.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn instruments_by_base_currency(
+        &self,
+        exchange: Exchange,
+        start: Option<u64>,
+        end: Option<u64>,
+        ts_init: Option<u64>,
+        filter: Option<&InstrumentFilter>,
+        normalize_symbols: Option<bool>,
+        timeout: Option<Duration>,
+    ) -> Result<HashMap<Currency, Vec<InstrumentAny>>> {
+        let options = InstrumentOptions {
+            normalize_symbols,
+            timeout,
+            ..Default::default()
+        };
+        let instruments = self
+            .instruments(exchange, start, end, ts_init, filter, &options)
+            .await?;
+
+        let mut grouped: HashMap<Currency, Vec<InstrumentAny>> = HashMap::new();
+        for instrument in instruments {
+            let base_currency = instrument.base_currency().or_else(|| {
+                instrument
+                    .underlying()
+                    .and_then(|underlying| Currency::try_from_str(underlying.as_str()))
+            });
+            match base_currency {
+                Some(base_currency) => grouped.entry(base_currency).or_default().push(instrument),
+                None => tracing::warn!("Skipping {} with no base currency", instrument.id()),
+            }
+        }
+        Ok(grouped)
+    }
+}
+
+/// Provides a builder for a [`TardisHttpClient`].
+///
+/// Unset options fall back to the `TARDIS_API_KEY` environment variable for the API key,
+/// the [`TardisEnv::Production`] base URL, a 60 second timeout and normalized symbols.
+#[derive(Clone, Default)]
+pub struct TardisHttpClientBuilder {
+    api_keys: Vec<String>,
+    api_key_file: Option<PathBuf>,
+    api_key_provider: Option<ApiKeyProvider>,
+    auth_style: AuthStyle,
+    api_version: Option<String>,
+    base_url: Option<String>,
+    env: TardisEnv,
+    timeout: Option<Duration>,
+    normalize_symbols: Option<bool>,
+    symbol_normalizer: Option<Arc<dyn SymbolNormalizer>>,
+    dedup_instruments: bool,
+    validate_filter: bool,
+    buffer_responses: bool,
+    max_response_bytes: Option<usize>,
+    user_agent: Option<String>,
+    default_headers: HashMap<String, String>,
+    pool_config: PoolConfig,
+    root_certificate_pems: Vec<Vec<u8>>,
+    accept_invalid_certs: bool,
+    proxy_url: Option<String>,
+    proxy_credentials: Option<(String, String)>,
+    retry_config: Option<RetryConfig>,
+    endpoint_retry_configs: HashMap<TardisEndpoint, RetryConfig>,
+    requests_per_second: Option<u32>,
+    max_concurrent_requests: Option<usize>,
+    cache_ttl: Option<Duration>,
+    conditional_requests: bool,
+    instrument_cache_ttls: Option<(Duration, Duration)>,
+    disk_cache: Option<DiskCache>,
+    circuit_breaker: Option<(u32, Duration)>,
+    metrics: Option<Arc<dyn TardisMetrics>>,
+    error_parser: Option<Arc<dyn ErrorBodyParser>>,
+    slow_request_threshold: Option<Duration>,
+    client: Option<reqwest::Client>,
+    #[cfg(feature = "vcr")]
+    vcr: Option<Arc<Vcr>>,
+}
+
+impl Debug for TardisHttpClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(TardisHttpClientBuilder))
+            .field("api_keys", &vec![API_KEY_MASK; self.api_keys.len()])
+            .field("api_key_file", &self.api_key_file)
+            .field("api_key_provider", &self.api_key_provider.is_some())
+            .field("auth_style", &self.auth_style)
+            .field("api_version", &self.api_version)
+            .field("base_url", &self.base_url)
+            .field("env", &self.env)
+            .field("timeout", &self.timeout)
+            .field("normalize_symbols", &self.normalize_symbols)
+            .field(
+                "custom_symbol_normalizer",
+                &self.symbol_normalizer.is_some(),
+            )
+            .field("dedup_instruments", &self.dedup_instruments)
+            .field("validate_filter", &self.validate_filter)
+            .field("buffer_responses", &self.buffer_responses)
+            .field("max_response_bytes", &self.max_response_bytes)
+            .field("user_agent", &self.user_agent)
+            .field("pool_config", &self.pool_config)
+            .field("accept_invalid_certs", &self.accept_invalid_certs)
+            .field("retry_config", &self.retry_config)
+            .field("endpoint_retry_configs", &self.endpoint_retry_configs)
+            .field("requests_per_second", &self.requests_per_second)
+            .field("max_concurrent_requests", &self.max_concurrent_requests)
+            .field("circuit_breaker", &self.circuit_breaker)
+            .field("custom_error_parser", &self.error_parser.is_some())
+            .field("slow_request_threshold", &self.slow_request_threshold)
+            .field("cache_ttl", &self.cache_ttl)
+            .field("conditional_requests", &self.conditional_requests)
+            .field("instrument_cache_ttls", &self.instrument_cache_ttls)
+            .field(
+                "disk_cache_dir",
+                &self.disk_cache.as_ref().map(DiskCache::dir),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
+impl TardisHttpClientBuilder {
+    /// Sets the Tardis API key.
+    #[must_use]
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_keys = vec![api_key.into()];
+        self
+    }
+
+    /// Sets a pool of Tardis API keys to rotate through.
+    ///
+    /// Requests are sent with the first key until it is rate limited (429) or rejected (401),
+    /// after which the next key is used, wrapping around at the end of the pool.
+    #[must_use]
+    pub fn api_keys<I, S>(mut self, api_keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.api_keys = api_keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets a file to load the Tardis API key from, such as a mounted secret.
+    ///
+    /// The file is read when the client is built (failing the build if it contains no key)
+    /// and again for every request, so a rotated key is picked up without rebuilding the
+    /// client. Surrounding whitespace is ignored. Takes precedence over
+    /// [`TardisHttpClientBuilder::api_keys`], but not over an API key provider.
+    #[must_use]
+    pub fn api_key_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.api_key_file = Some(path.into());
+        self
+    }
+
+    /// Sets a callback to load the Tardis API key, such as from a secret manager.
+    ///
+    /// The callback is called for every request, so a rotated key is picked up without
+    /// rebuilding the client, and should cache the key if loading it is expensive. A request
+    /// fails with [`Error::ApiKey`] if the callback fails. Takes precedence over
+    /// [`TardisHttpClientBuilder::api_keys`] and [`TardisHttpClientBuilder::api_key_file`].
+    #[must_use]
+    pub fn api_key_provider<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> anyhow::Result<String> + Send + Sync + 'static,
+    {
+        self.api_key_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Sets how the API key is attached to requests (defaults to [`AuthStyle::Bearer`]).
+    ///
+    /// A header name is validated when the client is built.
+    #[must_use]
+    pub fn auth_style(mut self, auth_style: AuthStyle) -> Self {
+        self.auth_style = auth_style;
+        self
+    }
+
+    /// Pins the Tardis API version, see [`TardisHttpClient::with_api_version`].
+    ///
+    /// The version is validated as a header value when the client is built.
+    #[must_use]
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
+
+    /// Sets the base URL for the Tardis HTTP API.
+    #[must_use]
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the Tardis environment to send requests to (production by default).
+    ///
+    /// An explicit [`TardisHttpClientBuilder::base_url`] takes precedence, for custom
+    /// deployments.
+    #[must_use]
+    pub const fn env(mut self, env: TardisEnv) -> Self {
+        self.env = env;
+        self
+    }
+
+    /// Sets the default timeout for each request.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether instrument symbols are normalized to Nautilus conventions.
+    #[must_use]
+    pub const fn normalize_symbols(mut self, normalize_symbols: bool) -> Self {
+        self.normalize_symbols = Some(normalize_symbols);
+        self
+    }
+
+    /// Sets a custom normalizer mapping raw Tardis symbols to Nautilus symbols.
+    ///
+    /// See [`TardisHttpClient::with_symbol_normalizer`].
+    #[must_use]
+    pub fn symbol_normalizer(mut self, normalizer: Arc<dyn SymbolNormalizer>) -> Self {
+        self.symbol_normalizer = Some(normalizer);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with each request, replacing the Nautilus default.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets additional headers to attach to every request.
+    ///
+    /// Header names and values are validated when the client is built.
+    #[must_use]
+    pub fn default_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open per host.
+    #[must_use]
+    pub const fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_config.max_idle_per_host = Some(max_idle);
+        self
+    }
+
+    /// Sets how long idle connections are kept open before being closed.
+    #[must_use]
+    pub const fn pool_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.pool_config.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Enables TCP keep-alive probes on connections with the given `interval`.
+    #[must_use]
+    pub const fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.pool_config.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Adds a PEM encoded root certificate to trust, in addition to the default roots
+    /// (e.g. for a gateway with a private certificate authority).
+    ///
+    /// The certificate is validated when the client is built.
+    #[must_use]
+    pub fn root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificate_pems.push(pem.into());
+        self
+    }
+
+    /// Disables validation of TLS certificates when `accept_invalid_certs` is true.
+    ///
+    /// # Warning
+    ///
+    /// This is **dangerous** and must only be used in isolated test environments:
+    /// any certificate will be trusted, including expired certificates and those for
+    /// other hosts, leaving requests and the API key open to interception.
+    /// Prefer [`Self::root_certificate_pem`] to trust a private certificate authority.
+    #[must_use]
+    pub const fn danger_accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Routes all requests through the HTTP(S) proxy at `proxy_url`, optionally
+    /// authenticating with the given `(username, password)` credentials.
+    #[must_use]
+    pub fn proxy(mut self, proxy_url: &str, credentials: Option<(&str, &str)>) -> Self {
+        self.proxy_url = Some(proxy_url.to_string());
+        self.proxy_credentials =
+            credentials.map(|(username, password)| (username.to_string(), password.to_string()));
+        self
+    }
+
+    /// Enables retrying of transient failures with exponential backoff and jitter.
+    ///
+    /// See [`TardisHttpClient::with_retries`].
+    #[must_use]
+    pub const fn retries(
+        mut self,
+        max_retries: u32,
+        base_delay: Duration,
+        max_delay: Duration,
+    ) -> Self {
+        self.retry_config = Some(RetryConfig::new(max_retries, base_delay, max_delay));
+        self
+    }
+
+    /// Sets the retry configuration for requests to `endpoint`.
+    ///
+    /// See [`TardisHttpClient::with_endpoint_retries`].
+    #[must_use]
+    pub fn endpoint_retries(mut self, endpoint: TardisEndpoint, retry_config: RetryConfig) -> Self {
+        self.endpoint_retry_configs.insert(endpoint, retry_config);
+        self
+    }
+
+    /// Caps the rate of requests sent at `requests_per_second`.
+    ///
+    /// See [`TardisHttpClient::with_rate_limit`].
+    #[must_use]
+    pub const fn rate_limit(mut self, requests_per_second: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second);
+        self
+    }
+
+    /// Caps the number of requests in flight at once at `max_concurrent_requests`.
+    ///
+    /// See [`TardisHttpClient::with_max_concurrent_requests`].
+    #[must_use]
+    pub const fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// Enables a circuit breaker which opens after `threshold` consecutive failures, failing
+    /// requests fast for `cooldown`.
+    ///
+    /// See [`TardisHttpClient::with_circuit_breaker`].
+    #[must_use]
+    pub const fn circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.circuit_breaker = Some((threshold, cooldown));
+        self
+    }
+
+    /// Enables in-memory caching of instrument metadata responses for the given `ttl`.
+    ///
+    /// See [`TardisHttpClient::with_cache`].
+    #[must_use]
+    pub const fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Enables in-memory caching of single instrument lookups, where found instruments are
+    /// cached for `ttl` and symbols which are not found for `not_found_ttl`.
+    ///
+    /// See [`TardisHttpClient::with_instrument_cache`].
+    #[must_use]
+    pub const fn instrument_cache_ttl(mut self, ttl: Duration, not_found_ttl: Duration) -> Self {
+        self.instrument_cache_ttls = Some((ttl, not_found_ttl));
+        self
+    }
+
+    /// Sets the on-disk cache of instrument metadata snapshots, stored in `dir`.
+    ///
+    /// See [`TardisHttpClient::with_disk_cache`].
+    #[must_use]
+    pub fn disk_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.disk_cache = Some(DiskCache::new(dir));
+        self
+    }
+
+    /// Sets whether instrument metadata is revalidated with conditional requests.
+    /// Defaults to false.
+    ///
+    /// See [`TardisHttpClient::with_conditional_requests`].
+    #[must_use]
+    pub const fn conditional_requests(mut self, conditional_requests: bool) -> Self {
+        self.conditional_requests = conditional_requests;
+        self
+    }
+
+    /// Sets whether [`TardisHttpClient::instruments`] collapses instruments which map to the
+    /// same instrument ID, such as alias listings when symbols are normalized.
+    ///
+    /// Only the instruments of the most recently updated exchange symbol are kept, and a
+    /// warning is logged for each dropped symbol. Defaults to false.
+    #[must_use]
+    pub const fn dedup_instruments(mut self, dedup_instruments: bool) -> Self {
+        self.dedup_instruments = dedup_instruments;
+        self
+    }
+
+    /// Sets whether [`TardisHttpClient::instruments_info`] checks each returned instrument
+    /// against the requested filter, logging a warning if any do not match, such as to
+    /// catch a filter the API interprets differently than intended. Defaults to false.
+    ///
+    /// See [`InstrumentFilter::mismatched_fields`] for the fields checked.
+    #[must_use]
+    pub const fn validate_filter(mut self, validate_filter: bool) -> Self {
+        self.validate_filter = validate_filter;
+        self
+    }
+
+    /// Sets whether response bodies are buffered in full before being parsed.
+    ///
+    /// By default responses are deserialized as they are received, which keeps peak memory
+    /// low for large instrument metadata responses (keeping only the start of the body
+    /// for the snippet in an [`Error::ResponseParse`]). Buffering allows the full raw body
+    /// to be logged at debug level when a response fails to parse.
+    #[must_use]
+    pub const fn buffer_responses(mut self, buffer_responses: bool) -> Self {
+        self.buffer_responses = buffer_responses;
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes, where zero removes the limit
+    /// (defaults to [`DEFAULT_MAX_RESPONSE_BYTES`]).
+    ///
+    /// See [`TardisHttpClient::with_max_response_bytes`].
+    #[must_use]
+    pub const fn max_response_bytes(mut self, max_response_bytes: usize) -> Self {
+        self.max_response_bytes = Some(max_response_bytes);
+        self
+    }
+
+    /// Registers `metrics` callbacks which are invoked around each HTTP request.
+    #[must_use]
+    pub fn metrics(mut self, metrics: Arc<dyn TardisMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sets a custom parser for the bodies of unsuccessful responses.
+    ///
+    /// See [`TardisHttpClient::with_error_parser`].
+    #[must_use]
+    pub fn error_parser(mut self, error_parser: Arc<dyn ErrorBodyParser>) -> Self {
+        self.error_parser = Some(error_parser);
+        self
+    }
+
+    /// Sets the latency threshold above which requests are logged as slow, see
+    /// [`TardisHttpClient::with_slow_request_threshold`].
+    #[must_use]
+    pub const fn slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets a `vcr` to record requests to, or play responses back from, its cassette.
+    #[cfg(feature = "vcr")]
+    #[must_use]
+    pub fn vcr(mut self, vcr: Arc<Vcr>) -> Self {
+        self.vcr = Some(vcr);
+        self
+    }
+
+    /// Sets a pre-built `client` to send requests with, instead of building one.
+    ///
+    /// The `client` is used as is, so the timeout, user agent, default headers, connection
+    /// pool and proxy options of this builder do not apply to it.
+    #[must_use]
+    pub fn client(mut self, client: reqwest::Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Builds the [`TardisHttpClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no API key was provided or set in the `TARDIS_API_KEY`
+    /// environment variable, if a provided API key is empty, if the API key file fails to
+    /// read or contains no key, if the auth header name or API version is invalid, if the
+    /// base URL is not a valid HTTP(S) URL, if a default header name or value is invalid,
+    /// if a root certificate or the proxy URL is invalid, or if the underlying HTTP client
+    /// fails to build.
+    pub fn build(self) -> anyhow::Result<TardisHttpClient> {
+        let api_keys = if let Some(provider) = self.api_key_provider {
+            ApiKeyPool::from_provider(provider)
+        } else if let Some(path) = self.api_key_file {
+            read_api_key_file(&path)?;
+            ApiKeyPool::from_provider(Arc::new(move || read_api_key_file(&path)))
+        } else if self.api_keys.is_empty() {
+            ApiKeyPool::new(vec![env::var("TARDIS_API_KEY").map_err(|_| {
+                anyhow::anyhow!(
+                    "API key must be provided or set in the 'TARDIS_API_KEY' environment variable"
+                )
+            })?])?
+        } else {
+            ApiKeyPool::new(self.api_keys)?
+        };
+        let api_keys = Arc::new(api_keys);
+        let auth_header = match &self.auth_style {
+            AuthStyle::Bearer => None,
+            AuthStyle::Header(name) => Some(
+                HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|e| anyhow::anyhow!("Invalid auth header name '{name}': {e}"))?,
+            ),
+        };
+        if let Some(api_version) = &self.api_version {
+            HeaderValue::from_str(api_version)
+                .map_err(|e| anyhow::anyhow!("Invalid API version '{api_version}': {e}"))?;
+        }
+
+        let base_url = match &self.base_url {
+            Some(base_url) => parse_base_url(base_url)?,
+            None => parse_base_url(&self.env.base_url()?)?,
+        };
+        let timeout = self.timeout.unwrap_or(Duration::from_secs(60));
+
+        let proxy = match &self.proxy_url {
+            Some(proxy_url) => Some(TardisHttpClient::build_proxy(
+                proxy_url,
+                self.proxy_credentials
+                    .as_ref()
+                    .map(|(username, password)| (username.as_str(), password.as_str())),
+            )?),
+            None => None,
+        };
+        let user_agent = self.user_agent.unwrap_or_else(|| USER_AGENT.to_string());
+        let default_headers = parse_default_headers(&self.default_headers)?;
+        let tls_config = TlsConfig {
+            root_certificates: self
+                .root_certificate_pems
+                .iter()
+                .map(|pem| {
+                    reqwest::Certificate::from_pem(pem)
+                        .map_err(|e| anyhow::anyhow!("Invalid root certificate: {e}"))
+                })
+                .collect::<anyhow::Result<_>>()?,
+            accept_invalid_certs: self.accept_invalid_certs,
+        };
+        let client = match self.client {
+            Some(client) => client,
+            None => TardisHttpClient::build_client(
+                timeout,
+                &user_agent,
+                default_headers.clone(),
+                self.pool_config,
+                &tls_config,
+                proxy,
+                auth_header.is_some(),
+            )?,
+        };
+
+        Ok(TardisHttpClient {
+            base_url,
+            api_keys,
+            auth_header,
+            api_version: self.api_version,
+            timeout,
+            user_agent,
+            default_headers,
+            pool_config: self.pool_config,
+            tls_config,
+            client,
+            normalize_symbols: self.normalize_symbols.unwrap_or(true),
+            symbol_normalizer: self.symbol_normalizer,
+            dedup_instruments: self.dedup_instruments,
+            validate_filter: self.validate_filter,
+            buffer_responses: self.buffer_responses,
+            max_response_bytes: Some(
+                self.max_response_bytes
+                    .unwrap_or(DEFAULT_MAX_RESPONSE_BYTES),
+            )
+            .filter(|limit| *limit > 0),
+            retry_config: self.retry_config,
+            endpoint_retry_configs: self.endpoint_retry_configs,
+            disk_cache: self.disk_cache,
+            state: Arc::new(SharedState {
+                retry_counters: RetryCounters::default(),
+                rate_limit: RateLimitState::default(),
+                request_limiter: self
+                    .requests_per_second
+                    .and_then(NonZeroU32::new)
+                    .map(TokenBucket::new),
+                concurrency_limit: self
+                    .max_concurrent_requests
+                    .and_then(NonZeroUsize::new)
+                    .map(ConcurrencyLimit::new),
+                instruments_cache: self.cache_ttl.map(TtlCache::new),
+                instruments_validators: self.conditional_requests.then(ValidatorCache::default),
+                circuit_breaker: self
+                    .circuit_breaker
+                    .map(|(threshold, cooldown)| CircuitBreaker::new(threshold, cooldown)),
+                instrument_cache: self
+                    .instrument_cache_ttls
+                    .map(|(ttl, not_found_ttl)| LookupCache::new(ttl, not_found_ttl)),
+            }),
+            metrics: self.metrics,
+            error_parser: self.error_parser,
+            slow_request_threshold: self.slow_request_threshold,
+            deadline: None,
+            #[cfg(feature = "vcr")]
+            vcr: self.vcr,
+        })
+    }
+}
+
+/// Flattens the per-exchange `results` of [`TardisHttpClient::instruments_multi`] into
+/// instruments tagged with the exchange they were requested from.
+///
+/// Exchanges whose request failed are skipped (their errors are logged by
+/// `instruments_multi`), so inspect `results` first if failures must be handled. The order
+/// of exchanges is unspecified, while the instruments of each exchange keep their order.
+pub fn tag_instruments_by_exchange(
+    results: HashMap<Exchange, Result<Vec<InstrumentAny>>>,
+) -> impl Iterator<Item = (Exchange, InstrumentAny)> {
+    results
+        .into_iter()
+        .filter_map(|(exchange, result)| result.ok().map(|instruments| (exchange, instruments)))
+        .flat_map(|(exchange, instruments)| {
+            instruments
+                .into_iter()
+                .map(move |instrument| (exchange.clone(), instrument))
+        })
+}
+
+/// Returns whether the response `body` is empty or only whitespace.
+fn is_blank(body: &[u8]) -> bool {
+    body.iter().all(u8::is_ascii_whitespace)
+}
+
+/// The API key a request was sent with, stored in the response extensions.
+#[derive(Clone)]
+struct SentApiKey(String);
+
+/// Deserializes the raw response `body` as JSON, validating UTF-8 as it is parsed
+/// rather than in a separate pass over the body, with any of the `secrets` masked in
+/// the snippet of a body which fails to parse.
+fn parse_json<T: DeserializeOwned>(status: u16, body: &[u8], secrets: &[String]) -> Result<T> {
+    if is_blank(body) {
+        tracing::error!("Empty response body");
+        return Err(Error::EmptyResponse { status });
+    }
+
+    match serde_json::from_slice(body) {
+        Ok(parsed) => Ok(parsed),
+        Err(e) => {
+            // Only converted to text on the error path
+            let body = String::from_utf8_lossy(body);
+            tracing::error!("Failed to parse response: {}", e);
+            tracing::debug!("Response body was: {}", body);
+            Err(Error::response_parse(status, e, &body, secrets))
+        }
+    }
+}
+
+/// The start of a response body deserialized while it is received, kept to describe
+/// parse errors without buffering the full body.
+#[derive(Debug)]
+struct BodyPrefix {
+    /// The start of the body, kept up to the `capacity`.
+    bytes: Vec<u8>,
+    /// One byte over [`RESPONSE_SNIPPET_MAX_LEN`] (so a longer body is marked as truncated
+    /// in the snippet) plus the length of the longest secret, so a secret crossing the end
+    /// of the snippet is received in full and masked before the snippet is truncated.
+    capacity: usize,
+    /// Whether the body was longer than the `capacity`.
+    truncated: bool,
+    /// Whether any non-whitespace content has been received.
+    has_content: bool,
+}
+
+impl BodyPrefix {
+    /// Creates a new [`BodyPrefix`] instance to mask any of the `secrets` with.
+    fn new(secrets: &[String]) -> Self {
+        let max_secret_len = secrets.iter().map(String::len).max().unwrap_or(0);
+        Self {
+            bytes: Vec::new(),
+            capacity: RESPONSE_SNIPPET_MAX_LEN + 1 + max_secret_len,
+            truncated: false,
+            has_content: false,
+        }
+    }
+
+    /// Records the received `chunk` of the body.
+    fn extend(&mut self, chunk: &[u8]) {
+        let remaining = self.capacity.saturating_sub(self.bytes.len());
+        self.truncated |= chunk.len() > remaining;
+        self.bytes
+            .extend_from_slice(&chunk[..remaining.min(chunk.len())]);
+        self.has_content |= !is_blank(chunk);
+    }
+
+    /// Returns the received body as text, without the start of any of the `secrets` cut
+    /// off at the end of a truncated body (which would otherwise not be masked).
+    ///
+    /// Masking the secrets can shorten the text below the snippet length, so whether the
+    /// body was truncated is recorded separately.
+    fn text(&self, secrets: &[String]) -> Cow<'_, str> {
+        let mut bytes = self.bytes.as_slice();
+        if self.truncated {
+            for secret in secrets {
+                let secret = secret.as_bytes();
+                if let Some(len) = (1..secret.len())
+                    .rev()
+                    .find(|&len| bytes.ends_with(&secret[..len]))
+                {
+                    bytes = &bytes[..bytes.len() - len];
+                }
+            }
+        }
+        String::from_utf8_lossy(bytes)
+    }
+}
+
+/// Maps an error deserializing a response body while it is received to the client error,
+/// describing parse errors with the body `prefix` (with any of the `secrets` masked).
+fn read_json_error(
+    status: u16,
+    error: serde_json::Error,
+    prefix: &BodyPrefix,
+    secrets: &[String],
+) -> Error {
+    if error.is_io() {
+        return unwrap_io_error(error.into());
+    }
+    if error.is_eof() && !prefix.has_content {
+        tracing::error!("Empty response body");
+        return Error::EmptyResponse { status };
+    }
+
+    tracing::error!("Failed to parse response: {error}");
+    Error::response_parse_prefix(
+        status,
+        error,
+        &prefix.text(secrets),
+        prefix.truncated,
+        secrets,
+    )
+}
+
+/// Visits a JSON array, sending each element which matches the `predicate` over the `tx`
+/// channel rather than collecting the elements.
+struct FilteredSeqVisitor<T, P> {
+    predicate: P,
+    tx: mpsc::Sender<Result<T>>,
+}
+
+impl<'de, T, P> Visitor<'de> for FilteredSeqVisitor<T, P>
+where
+    T: Deserialize<'de>,
+    P: Fn(&T) -> bool,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON array")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<(), A::Error> {
+        while let Some(element) = seq.next_element::<T>()? {
+            if (self.predicate)(&element) && self.tx.blocking_send(Ok(element)).is_err() {
+                return Err(de::Error::custom("Receiver dropped"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the redirect policy of the underlying client, see [`TardisHttpClient`].
+fn redirect_policy(custom_auth_header: bool) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        let previous = attempt.previous();
+        let is_loop = previous.contains(attempt.url());
+        let redirects = previous.len();
+        let is_cross_origin = previous
+            .last()
+            .is_some_and(|url| url.origin() != attempt.url().origin());
+
+        if is_loop {
+            attempt.error("redirect loop")
+        } else if redirects > MAX_REDIRECTS {
+            attempt.error(format!("exceeded the maximum of {MAX_REDIRECTS} redirects"))
+        } else if custom_auth_header && is_cross_origin {
+            attempt.error("cross-origin redirect would forward the API key header")
+        } else {
+            attempt.follow()
+        }
+    })
+}
+
+/// Converts a failed request `error` into an [`Error::Redirect`] if a redirect was not
+/// followed, otherwise an [`Error::Request`].
+fn redirect_error(error: reqwest::Error) -> Error {
+    if !error.is_redirect() {
+        return Error::Request(error);
+    }
+
+    let url = error.url().map(ToString::to_string).unwrap_or_default();
+    let message =
+        std::error::Error::source(&error).map_or_else(|| error.to_string(), ToString::to_string);
+    Error::Redirect { url, message }
+}
+
+/// Returns the [`Error`] raised while reading a response body from within the I/O `error`
+/// (such as [`Error::ResponseTooLarge`]), or otherwise wraps it as an [`Error::Io`].
+fn unwrap_io_error(error: std::io::Error) -> Error {
+    if !error.get_ref().is_some_and(|inner| inner.is::<Error>()) {
+        return Error::Io(error);
+    }
+
+    let inner = error.into_inner().expect("Inner error was checked");
+    *inner
+        .downcast::<Error>()
+        .expect("Inner error type was checked")
+}
+
+/// Parses each of the Tardis instrument `infos`, returning the Nautilus instrument
+/// definitions along with the definitions which failed to parse (each logged as a warning).
+fn parse_instrument_infos(
+    infos: Vec<InstrumentInfo>,
+    start: Option<u64>,
+    end: Option<u64>,
+    ts_init: Option<UnixNanos>,
+    normalizer: &dyn SymbolNormalizer,
+) -> (Vec<InstrumentAny>, Vec<SkippedInstrument>) {
+    let mut instruments = Vec::with_capacity(infos.len());
+    let mut skipped = Vec::new();
+
+    for info in infos {
+        match parse_instrument_any_with_normalizer(&info, start, end, ts_init, normalizer) {
+            Ok(parsed) => instruments.extend(parsed),
+            Err(error) => {
+                tracing::warn!("Skipping instrument {}: {error}", info.id);
+                skipped.push(SkippedInstrument { info, error });
+            }
+        }
+    }
+
+    (instruments, skipped)
+}
+
+/// Collapses instruments from different raw symbols which map to the same instrument ID
+/// (such as alias listings), keeping those of the most recently updated raw symbol.
+///
+/// All instruments (one per specification change) of the kept raw symbol are retained.
+fn dedup_instruments(mut instruments: Vec<InstrumentAny>) -> Vec<InstrumentAny> {
+    let mut latest: HashMap<InstrumentId, (Symbol, UnixNanos)> = HashMap::new();
+    for instrument in &instruments {
+        let raw_symbol = instrument.raw_symbol();
+        let ts_event = as_instrument(instrument).ts_event();
+
+        match latest.entry(instrument.id()) {
+            Entry::Vacant(entry) => {
+                entry.insert((raw_symbol, ts_event));
+            }
+            Entry::Occupied(mut entry) => {
+                let (kept_symbol, kept_ts_event) = entry.get_mut();
+                if ts_event > *kept_ts_event {
+                    *kept_symbol = raw_symbol;
+                    *kept_ts_event = ts_event;
+                }
+            }
+        }
+    }
+
+    let mut dropped = HashSet::new();
+    instruments.retain(|instrument| {
+        let instrument_id = instrument.id();
+        let raw_symbol = instrument.raw_symbol();
+        let (kept_symbol, _) = latest[&instrument_id];
+        if raw_symbol == kept_symbol {
+            return true;
+        }
+        if dropped.insert(raw_symbol) {
+            tracing::warn!(
+                "Dropping instrument {raw_symbol} as {instrument_id} collides with more recently updated {kept_symbol}"
+            );
+        }
+        false
+    });
+    instruments
+}
+
+/// Logs a warning if any of the Tardis instrument `infos` returned for `exchange` do not
+/// match the requested `filter`, returning the number which do not.
+fn warn_filter_mismatches(
+    exchange: &Exchange,
+    filter: &InstrumentFilter,
+    infos: &[InstrumentInfo],
+) -> usize {
+    let mut mismatches = 0;
+    for info in infos {
+        let fields = filter.mismatched_fields(info);
+        if !fields.is_empty() {
+            mismatches += 1;
+            tracing::debug!(
+                "Instrument {} for {exchange} does not match the filter on {fields:?}",
+                info.id
+            );
+        }
+    }
+
+    if mismatches > 0 {
+        tracing::warn!(
+            "{mismatches} of {} instruments for {exchange} do not match the requested filter",
+            infos.len()
+        );
+    }
+    mismatches
+}
+
+/// Returns whether the parsed `instrument` passes the parts of the `filter` which the
+/// metadata API does not apply (settlement currency and Bybit product category).
+fn matches_parsed_filter(filter: Option<&InstrumentFilter>, instrument: &InstrumentAny) -> bool {
+    filter.is_none_or(|filter| {
+        filter.matches_settlement_currency(instrument.settlement_currency().code.as_str())
+            && filter.matches_bybit_category(&instrument.id())
+    })
+}
+
+/// Returns a span with a unique `request_id`, which correlates all logs for a single call
+/// when many requests are in flight.
+fn request_span(method: &'static str, exchange: &Exchange) -> tracing::Span {
+    tracing::debug_span!("tardis_request", request_id = %UUID4::new(), method, %exchange)
+}
+
+/// Awaits the given `future`, unless the optional `cancellation` token is cancelled first.
+async fn with_cancellation<T>(
+    cancellation: Option<&CancellationToken>,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match cancellation {
+        Some(token) => tokio::select! {
+            biased;
+            () = token.cancelled() => Err(Error::Cancelled),
+            result = future => result,
+        },
+        None => future.await,
+    }
+}
+
+/// Validates that `base_url` is an absolute HTTP(S) URL, returning it without any trailing
+/// slash so that endpoint paths can be appended directly.
+fn parse_base_url(base_url: &str) -> anyhow::Result<String> {
+    let url = reqwest::Url::parse(base_url)
+        .map_err(|e| anyhow::anyhow!("Invalid base URL '{base_url}': {e}"))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        anyhow::bail!(
+            "Invalid base URL '{base_url}': scheme must be 'http' or 'https', was '{}'",
+            url.scheme()
+        );
+    }
+    Ok(base_url.trim_end_matches('/').to_string())
+}
+
+fn parse_default_headers(headers: &HashMap<String, String>) -> anyhow::Result<HeaderMap> {
+    let mut header_map = HeaderMap::with_capacity(headers.len());
+    for (name, value) in headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| anyhow::anyhow!("Invalid header name '{name}': {e}"))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|e| anyhow::anyhow!("Invalid value for header '{name}': {e}"))?;
+        header_map.insert(header_name, header_value);
+    }
+    Ok(header_map)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+#[cfg(target_os = "linux")] // Only run network tests on Linux (CI stability)
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use axum::{http::StatusCode, response::IntoResponse, routing::get, Router};
+    use nautilus_model::{enums::InstrumentClass, types::Price};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use rstest::rstest;
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::{
+        http::{
+            error::JsonErrorParser, export::instruments_schema, query::ActiveFilter,
+            replay::ReplayFilter, TARDIS_BASE_URL,
+        },
+        tests::{load_test_json, start_mock_server},
+    };
+
+    fn instruments_json() -> String {
+        format!("[{}]", load_test_json("instrument_spot.json"))
+    }
+
+    /// Returns a router which fails with `status` for the first `failures` requests.
+    fn flaky_router(status: StatusCode, failures: usize, counter: Arc<AtomicUsize>) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let counter = counter.clone();
+                async move {
+                    let count = counter.fetch_add(1, Ordering::SeqCst);
+                    if count < failures {
+                        (
+                            status,
+                            "{\"code\":0,\"message\":\"Unavailable\"}".to_string(),
+                        )
+                            .into_response()
+                    } else {
+                        (StatusCode::OK, instruments_json()).into_response()
+                    }
+                }
+            }),
+        )
+    }
+
+    fn test_client(base_url: &str) -> TardisHttpClient {
+        TardisHttpClient::new(Some("test-key"), Some(base_url), None, false).unwrap()
+    }
+
+    /// Responds with `status` to requests authenticated with `rejected_key`.
+    fn key_router(status: StatusCode, rejected_key: &'static str) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| async move {
+                let auth = headers
+                    .get("authorization")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default();
+                if auth == format!("Bearer {rejected_key}") {
+                    (status, "{\"code\":0,\"message\":\"Rejected\"}".to_string()).into_response()
+                } else {
+                    (StatusCode::OK, instruments_json()).into_response()
+                }
+            }),
+        )
+    }
+
+    fn key_pool_client(base_url: &str) -> TardisHttpClient {
+        TardisHttpClient::builder()
+            .api_keys(["key-1", "key-2"])
+            .base_url(base_url)
+            .build()
+            .unwrap()
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rotates_api_key_on_rate_limit() {
+        let base_url = start_mock_server(key_router(StatusCode::TOO_MANY_REQUESTS, "key-1")).await;
+        let client = key_pool_client(&base_url).with_retries(
+            1,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+        assert_eq!(client.active_key_index(), 0);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert_eq!(client.active_key_index(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rotates_api_key_on_unauthorized() {
+        let base_url = start_mock_server(key_router(StatusCode::UNAUTHORIZED, "key-1")).await;
+        let client = key_pool_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert!(matches!(result, Err(Error::Unauthorized { .. })));
+        assert_eq!(client.active_key_index(), 1);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_api_key_file_is_reread_for_each_request() {
+        let base_url = start_mock_server(key_router(StatusCode::UNAUTHORIZED, "old-key")).await;
+        let path = env::temp_dir().join(format!("tardis-key-{}.txt", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "old-key\n").unwrap();
+        let client = TardisHttpClient::builder()
+            .api_key_file(&path)
+            .base_url(&base_url)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert!(matches!(result, Err(Error::Unauthorized { .. })));
+
+        // Rotated secret is picked up without rebuilding the client
+        std::fs::write(&path, "new-key\n").unwrap();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert_eq!(result.unwrap().len(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert!(matches!(result, Err(Error::ApiKey(_))), "{result:?}");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_api_key_provider_is_called_for_each_request() {
+        let base_url = start_mock_server(key_router(StatusCode::UNAUTHORIZED, "old-key")).await;
+        let secret = Arc::new(std::sync::Mutex::new("old-key".to_string()));
+        let secret_clone = secret.clone();
+        let client = TardisHttpClient::builder()
+            .api_key("ignored-key")
+            .api_key_provider(move || Ok(secret_clone.lock().unwrap().clone()))
+            .base_url(&base_url)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert!(matches!(result, Err(Error::Unauthorized { .. })));
+
+        *secret.lock().unwrap() = "new-key".to_string();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_api_key_provider_error() {
+        let client = TardisHttpClient::builder()
+            .api_key_provider(|| anyhow::bail!("Vault unavailable"))
+            .base_url("http://127.0.0.1:9")
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert!(
+            matches!(&result, Err(Error::ApiKey(message)) if message == "Vault unavailable"),
+            "{result:?}"
+        );
+    }
+
+    #[rstest]
+    fn test_builder_rejects_empty_api_key() {
+        assert!(TardisHttpClient::builder().api_keys([""]).build().is_err());
+    }
+
+    #[rstest]
+    fn test_builder_rejects_missing_api_key_file() {
+        let path = env::temp_dir().join(format!("tardis-key-{}.txt", uuid::Uuid::new_v4()));
+        assert!(TardisHttpClient::builder()
+            .api_key_file(path)
+            .build()
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_builder_env_and_base_url_override() {
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .env(TardisEnv::Production)
+            .build()
+            .unwrap();
+        let custom = TardisHttpClient::builder()
+            .api_key("test-key")
+            .env(TardisEnv::Sandbox)
+            .base_url("https://tardis.example.com/v1/")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url, TARDIS_BASE_URL);
+        assert_eq!(custom.base_url, "https://tardis.example.com/v1");
+    }
+
+    #[rstest]
+    #[case("api.tardis.dev")]
+    #[case("api.tardis.dev/v1")]
+    #[case("ftp://api.tardis.dev/v1")]
+    #[case("")]
+    fn test_new_rejects_invalid_base_url(#[case] base_url: &str) {
+        let result = TardisHttpClient::new(Some("test-key"), Some(base_url), None, true);
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Invalid base URL"), "{err}");
+    }
+
+    #[rstest]
+    #[case("https://api.tardis.dev/v1", "https://api.tardis.dev/v1")]
+    #[case("https://api.tardis.dev/v1/", "https://api.tardis.dev/v1")]
+    #[case("http://127.0.0.1:8080//", "http://127.0.0.1:8080")]
+    fn test_new_normalizes_base_url(#[case] base_url: &str, #[case] expected: &str) {
+        let client = TardisHttpClient::new(Some("test-key"), Some(base_url), None, true).unwrap();
+
+        assert!(format!("{client:?}").contains(&format!("base_url: \"{expected}\"")));
+        assert_eq!(
+            client.instruments_info_url(&Exchange::Deribit, None),
+            format!("{expected}/instruments/deribit"),
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_retries_on_service_unavailable() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(
+            StatusCode::SERVICE_UNAVAILABLE,
+            2,
+            counter.clone(),
+        ))
+        .await;
+        let client = test_client(&base_url).with_retries(
+            3,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    /// Returns a router which redirects instruments requests to `location`, and serves the
+    /// instruments at `/moved/{exchange}` only to requests authenticated with `test-key`.
+    fn redirect_router(location: String) -> Router {
+        Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(move || {
+                    let location = location.clone();
+                    async move {
+                        (
+                            StatusCode::FOUND,
+                            [(axum::http::header::LOCATION, location)],
+                        )
+                            .into_response()
+                    }
+                }),
+            )
+            .route(
+                "/moved/{exchange}",
+                get(|headers: axum::http::HeaderMap| async move {
+                    let authorized = headers
+                        .get("authorization")
+                        .or_else(|| headers.get("x-api-key"))
+                        .and_then(|v| v.to_str().ok())
+                        .is_some_and(|auth| auth.ends_with("test-key"));
+                    if authorized {
+                        (StatusCode::OK, instruments_json()).into_response()
+                    } else {
+                        (
+                            StatusCode::UNAUTHORIZED,
+                            "{\"code\":0,\"message\":\"No key\"}",
+                        )
+                            .into_response()
+                    }
+                }),
+            )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_same_origin_redirect_keeps_auth() {
+        let base_url = start_mock_server(redirect_router("/moved/deribit".to_string())).await;
+        let client = test_client(&base_url);
+
+        let instruments = client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(instruments.len(), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_cross_origin_redirect() {
+        let target_url = start_mock_server(redirect_router(String::new())).await;
+        let base_url =
+            start_mock_server(redirect_router(format!("{target_url}/moved/deribit"))).await;
+
+        // The bearer token is dropped, so the target rejects the request
+        let result = test_client(&base_url)
+            .instruments_info(Exchange::Deribit, None, None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Unauthorized { status: 401, .. })
+        ));
+
+        // A custom key header would be forwarded, so the redirect is not followed
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .auth_style(AuthStyle::Header("x-api-key".to_string()))
+            .base_url(&base_url)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert!(matches!(result, Err(Error::Redirect { .. })));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_redirect_loop() {
+        let base_url = start_mock_server(redirect_router("/instruments/deribit".to_string())).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::Redirect { url, message }) => {
+                assert!(url.ends_with("/instruments/deribit"), "url {url}");
+                assert_eq!(message, "redirect loop");
+            }
+            other => panic!("Expected redirect error, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_idempotency_key_reused_across_retries() {
+        let keys = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let keys_clone = keys.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let keys = keys_clone.clone();
+                async move {
+                    let mut keys = keys.lock().unwrap();
+                    keys.push(
+                        headers
+                            .get(HEADER_IDEMPOTENCY_KEY)
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string(),
+                    );
+                    // Every other request fails, so each call succeeds on its retry
+                    if keys.len() % 2 == 1 {
+                        (StatusCode::SERVICE_UNAVAILABLE, String::new()).into_response()
+                    } else {
+                        (StatusCode::OK, instruments_json()).into_response()
+                    }
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url).with_retries(
+            3,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+
+        for _ in 0..2 {
+            client
+                .instruments_info(Exchange::Deribit, None, None)
+                .await
+                .unwrap();
+        }
+
+        let keys = keys.lock().unwrap();
+        assert_eq!(keys.len(), 4);
+        assert!(!keys[0].is_empty());
+        assert_eq!(keys[0], keys[1]);
+        assert_eq!(keys[2], keys[3]);
+        assert_ne!(keys[0], keys[2]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_request_logs_include_request_id() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter)).await;
+        let client = test_client(&base_url);
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert!(logs_contain("tardis_request{request_id="));
+        assert!(logs_contain("method=\"instruments_info\""));
+        assert!(logs_contain("Requesting"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_no_retry_on_client_error() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url =
+            start_mock_server(flaky_router(StatusCode::UNAUTHORIZED, 1, counter.clone())).await;
+        let client = test_client(&base_url).with_retries(
+            3,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Unauthorized { status: 401, .. })
+        ));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_limit_spaces_requests() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_rate_limit(10);
+
+        // The first 10 requests are sent as a burst, the remaining 5 at 10 per second
+        let start = Instant::now();
+        let results = future::join_all(
+            (0..15).map(|_| client.instruments_info(Exchange::Deribit, None, None)),
+        )
+        .await;
+        let elapsed = start.elapsed();
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(counter.load(Ordering::SeqCst), 15);
+        assert!(elapsed >= Duration::from_millis(450), "elapsed {elapsed:?}");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_limit_shared_by_clones_across_tasks() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_rate_limit(10);
+
+        // Each task alone would fit in the burst, but together the last 5 requests are
+        // sent at 10 per second
+        let start = Instant::now();
+        let handles: Vec<_> = (0..5)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    for _ in 0..3 {
+                        client
+                            .instruments_info(Exchange::Deribit, None, None)
+                            .await
+                            .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        let elapsed = start.elapsed();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 15);
+        assert!(elapsed >= Duration::from_millis(450), "elapsed {elapsed:?}");
+        assert_eq!(client.retry_stats().attempts, 15);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_disk_cache_miss_then_hit() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let dir = env::temp_dir().join(format!("tardis-cache-{}", uuid::Uuid::new_v4()));
+        let client = |base_url: &str| {
+            TardisHttpClient::builder()
+                .api_key("test-key")
+                .base_url(base_url)
+                .disk_cache(&dir)
+                .build()
+                .unwrap()
+        };
+
+        // A miss requests the instruments and writes the snapshot
+        let fetched = client(&base_url)
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        let key = DiskCache::key(
+            &Exchange::Deribit,
+            &InstrumentFilter::default(),
+            Utc::now().date_naive(),
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert!(DiskCache::new(&dir).path(&key).exists());
+
+        // A hit is read from the snapshot by a new client, without a server
+        let cached = client("http://127.0.0.1:9")
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(cached.len(), fetched.len());
+        assert_eq!(cached[0].id, fetched[0].id);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_max_concurrent_requests() {
+        let in_flight = Arc::new(AtomicUsize::new(0));
+        let max_in_flight = Arc::new(AtomicUsize::new(0));
+        let tracked = {
+            let (in_flight, max_in_flight) = (in_flight.clone(), max_in_flight.clone());
+            move |body: String| {
+                let (in_flight, max_in_flight) = (in_flight.clone(), max_in_flight.clone());
+                async move {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    body
+                }
+            }
+        };
+        let tracked_clone = tracked.clone();
+        let router = Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(move || tracked(instruments_json())),
+            )
+            .route(
+                "/exchanges",
+                get(move || tracked_clone(load_test_json("exchanges.json"))),
+            );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .max_concurrent_requests(2)
+            .build()
+            .unwrap();
+
+        // Requests from different methods and clones draw from the same permits
+        let handles: Vec<_> = (0..10)
+            .map(|i| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    if i % 2 == 0 {
+                        client
+                            .instruments_info(Exchange::Deribit, None, None)
+                            .await
+                            .map(|_| ())
+                    } else {
+                        client.exchanges(None).await.map(|_| ())
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    fn test_reconfigured_clone_detaches_state() {
+        let client = test_client("http://localhost").with_rate_limit(10);
+        let clone = client.clone();
+        let reconfigured = client.clone().with_cache(Duration::from_secs(60));
+
+        assert!(Arc::ptr_eq(&client.state, &clone.state));
+        assert!(!Arc::ptr_eq(&client.state, &reconfigured.state));
+        assert!(client.state.instruments_cache.is_none());
+        assert_eq!(
+            reconfigured
+                .state
+                .request_limiter
+                .as_ref()
+                .map(TokenBucket::requests_per_second),
+            NonZeroU32::new(10)
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_circuit_breaker_fails_fast_then_recovers() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            2,
+            counter.clone(),
+        ))
+        .await;
+        let client = test_client(&base_url).with_circuit_breaker(2, Duration::from_millis(200));
+
+        for _ in 0..2 {
+            let result = client.instruments_info(Exchange::Deribit, None, None).await;
+            assert!(matches!(
+                result,
+                Err(Error::ServerError { status: 500, .. })
+            ));
+        }
+
+        // Clones share the open circuit, failing fast without a request
+        let result = client
+            .clone()
+            .instruments_info(Exchange::Deribit, None, None)
+            .await;
+        assert!(matches!(result, Err(Error::CircuitOpen { .. })));
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+
+        // The half-open probe succeeds and closes the circuit
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_retries_exhausted_reports_attempts() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url =
+            start_mock_server(flaky_router(StatusCode::BAD_GATEWAY, 10, counter.clone())).await;
+        let client = test_client(&base_url).with_retries(
+            2,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::RetriesExhausted { attempts, source }) => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(*source, Error::ServerError { status: 502, .. }));
+            }
+            other => panic!("Expected `RetriesExhausted`, was {other:?}"),
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_endpoint_retries() {
+        let list_counter = Arc::new(AtomicUsize::new(0));
+        let info_counter = Arc::new(AtomicUsize::new(0));
+        let flaky = |counter: Arc<AtomicUsize>, body: fn() -> String| {
+            move || {
+                let counter = counter.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                        (StatusCode::SERVICE_UNAVAILABLE, String::new()).into_response()
+                    } else {
+                        (StatusCode::OK, body()).into_response()
+                    }
+                }
+            }
+        };
+        let router = Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(flaky(list_counter.clone(), instruments_json)),
+            )
+            .route(
+                "/instruments/{exchange}/{symbol}",
+                get(flaky(info_counter.clone(), || {
+                    load_test_json("instrument_spot.json")
+                })),
+            );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url)
+            .with_endpoint_retries(
+                TardisEndpoint::InstrumentsInfo,
+                RetryConfig::new(0, Duration::from_millis(10), Duration::from_millis(50)),
+            )
+            .with_endpoint_retries(
+                TardisEndpoint::InstrumentInfo,
+                RetryConfig::new(3, Duration::from_millis(10), Duration::from_millis(50)),
+            );
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+        let info = client
+            .instrument_info(Exchange::Deribit, "BTC_USDC", None)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            result,
+            Err(Error::RetriesExhausted { attempts: 1, .. })
+        ));
+        assert_eq!(list_counter.load(Ordering::SeqCst), 1);
+        assert_eq!(info.id.as_str(), "BTC_USDC");
+        assert_eq!(info_counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_retry_stats() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(
+            StatusCode::SERVICE_UNAVAILABLE,
+            2,
+            counter.clone(),
+        ))
+        .await;
+        let client = test_client(&base_url).with_retries(
+            2,
+            Duration::from_millis(10),
+            Duration::from_millis(50),
+        );
+        assert_eq!(client.retry_stats(), RetryStats::default());
+
+        // Succeeds on the third attempt
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        // Succeeds on the first attempt, counted by a clone
+        client
+            .clone()
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            client.retry_stats(),
+            RetryStats {
+                attempts: 4,
+                retried_requests: 1,
+                exhausted_retries: 0,
+            }
+        );
+
+        // Fails every attempt
+        counter.store(0, Ordering::SeqCst);
+        let exhausted =
+            client
+                .clone()
+                .with_retries(1, Duration::from_millis(10), Duration::from_millis(50));
+        let result = exhausted
+            .instruments_info(Exchange::Deribit, None, None)
+            .await;
+        assert!(matches!(result, Err(Error::RetriesExhausted { .. })));
+
+        assert_eq!(
+            client.retry_stats(),
+            RetryStats {
+                attempts: 6,
+                retried_requests: 2,
+                exhausted_retries: 1,
+            }
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_waits_for_rate_limit_reset() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let counter = counter_clone.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    (
+                        [("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "1")],
+                        instruments_json(),
+                    )
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        assert_eq!(client.remaining_requests(), None);
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        assert_eq!(client.remaining_requests(), Some(0));
+
+        // The budget is shared with clones of the client
+        let start = Instant::now();
+        client
+            .clone()
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(900));
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_cache_hit_within_ttl() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_cache(Duration::from_secs(60));
+        let filter = InstrumentFilter {
+            active: ActiveFilter::Active,
+            ..Default::default()
+        };
+
+        let first = client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+        let second = client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(first.len(), second.len());
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+        // A different filter is a different cache entry
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    /// Responds with a 404 for the `MISSING` symbol, counting the requests.
+    fn instrument_router(counter: Arc<AtomicUsize>) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}/{symbol}",
+            get(
+                move |axum::extract::Path((_, symbol)): axum::extract::Path<(String, String)>| {
+                    let counter = counter.clone();
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        if symbol == "MISSING" {
+                            (
+                                StatusCode::NOT_FOUND,
+                                "{\"code\":404,\"message\":\"Instrument not found\"}",
+                            )
+                                .into_response()
+                        } else {
+                            (StatusCode::OK, load_test_json("instrument_spot.json")).into_response()
+                        }
+                    }
+                },
+            ),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instrument_cache_not_found_within_ttl() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(instrument_router(counter.clone())).await;
+        let client = test_client(&base_url)
+            .with_instrument_cache(Duration::from_secs(60), Duration::from_secs(60));
+
+        for _ in 0..2 {
+            let result = client
+                .instrument_info(Exchange::Deribit, "MISSING", None)
+                .await;
+            match result {
+                Err(Error::NotFound { message, .. }) => {
+                    assert_eq!(message, "Instrument not found");
+                }
+                other => panic!("Expected `NotFound`, was {other:?}"),
+            }
+        }
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instrument_cache_separate_ttls() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(instrument_router(counter.clone())).await;
+        let client =
+            test_client(&base_url).with_instrument_cache(Duration::from_secs(60), Duration::ZERO);
+
+        for _ in 0..2 {
+            client
+                .instrument_info(Exchange::Deribit, "BTC_USDC", None)
+                .await
+                .unwrap();
+            let _ = client
+                .instrument_info(Exchange::Deribit, "MISSING", None)
+                .await;
+        }
+
+        // Found instrument served from the cache, expired not found result requested again
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_warm_cache_serves_subsequent_requests() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_cache(Duration::from_secs(60));
+        let exchanges = [Exchange::Deribit, Exchange::Bitmex];
+
+        client.warm_cache(&exchanges, None, None).await.unwrap();
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        for exchange in exchanges {
+            let instruments = client.instruments_info(exchange, None, None).await.unwrap();
+            assert_eq!(instruments.len(), 1);
+        }
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_warm_cache_shared_by_clones() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_cache(Duration::from_secs(60));
+        let clone = client.clone();
+
+        client
+            .warm_cache(&[Exchange::Deribit], None, None)
+            .await
+            .unwrap();
+        let instruments = tokio::spawn(async move {
+            clone
+                .instruments_info(Exchange::Deribit, None, None)
+                .await
+                .unwrap()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(instruments.len(), 1);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_validate_filter_warns_on_mismatch() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter)).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .validate_filter(true)
+            .build()
+            .unwrap();
+        let filter = InstrumentFilter {
+            base_currency: Some(vec!["ETH".to_string()]),
+            ..Default::default()
+        };
+
+        let instruments = client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(instruments.len(), 1);
+        assert!(logs_contain(
+            "1 of 1 instruments for deribit do not match the requested filter"
+        ));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_clear_cache_forces_refresh() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_cache(Duration::from_secs(60));
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        client.clear_cache();
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_conditional_request_not_modified_returns_stored_response() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get({
+                let counter = counter.clone();
+                move |headers: axum::http::HeaderMap| async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    if headers
+                        .get("if-none-match")
+                        .is_some_and(|etag| etag == "\"v1\"")
+                    {
+                        // The body would fail to parse if it were not ignored
+                        (StatusCode::NOT_MODIFIED, "not json").into_response()
+                    } else {
+                        (StatusCode::OK, [("etag", "\"v1\"")], instruments_json()).into_response()
+                    }
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url).with_conditional_requests();
+
+        let first = client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+        let second = client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        assert_eq!(first.len(), 1);
+        assert_eq!(second.len(), first.len());
+        assert_eq!(second[0].id, first[0].id);
+        assert!(logs_contain("Instruments for deribit not modified"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_multi_reports_per_exchange_results() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(
+                |axum::extract::Path(exchange): axum::extract::Path<String>| async move {
+                    if exchange == "bitmex" {
+                        (StatusCode::INTERNAL_SERVER_ERROR, String::new()).into_response()
+                    } else {
+                        (StatusCode::OK, instruments_json()).into_response()
+                    }
+                },
+            ),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let results = client
+            .instruments_multi(
+                &[Exchange::Deribit, Exchange::Bitmex, Exchange::Binance],
+                None,
+                None,
+                None,
+                None,
+                2,
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(results.len(), 3);
+        assert!(!results[&Exchange::Deribit].as_ref().unwrap().is_empty());
+        assert!(!results[&Exchange::Binance].as_ref().unwrap().is_empty());
+        assert!(matches!(
+            results[&Exchange::Bitmex],
+            Err(Error::ServerError { status: 500, .. })
+        ));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_spawn_auto_refresh() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                // A new listing appears from the second refresh
+                let body = if counter_clone.fetch_add(1, Ordering::SeqCst) == 0 {
+                    format!("[{}]", load_test_json("instrument_perpetual.json"))
+                } else {
+                    format!(
+                        "[{},{}]",
+                        load_test_json("instrument_perpetual.json"),
+                        load_test_json("instrument_future.json")
+                    )
+                };
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let (handle, mut rx) =
+            client.spawn_auto_refresh(&[Exchange::Deribit], Duration::from_millis(50));
+
+        for expected_len in [1, 2] {
+            tokio::time::timeout(Duration::from_secs(5), rx.changed())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(
+                rx.borrow_and_update()[&Exchange::Deribit].len(),
+                expected_len
+            );
+        }
+
+        // Dropping the receiver stops the task
+        drop(rx);
+        tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(counter.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_tag_instruments_by_exchange() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(
+                |axum::extract::Path(exchange): axum::extract::Path<String>| async move {
+                    if exchange == "bitmex" {
+                        return (StatusCode::INTERNAL_SERVER_ERROR, String::new()).into_response();
+                    }
+                    let mut info: serde_json::Value =
+                        serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+                    info["exchange"] = exchange.into();
+                    (StatusCode::OK, format!("[{info}]")).into_response()
+                },
+            ),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let results = client
+            .instruments_multi(
+                &[Exchange::Deribit, Exchange::Bitmex, Exchange::Binance],
+                None,
+                None,
+                None,
+                None,
+                2,
+                None,
+                None,
+            )
+            .await;
+        let expected_len: usize = results
+            .values()
+            .filter_map(|result| result.as_ref().ok())
+            .map(Vec::len)
+            .sum();
+
+        let tagged: Vec<(Exchange, InstrumentAny)> = tag_instruments_by_exchange(results).collect();
+
+        assert_eq!(tagged.len(), expected_len);
+        assert!(tagged
+            .iter()
+            .any(|(exchange, _)| *exchange == Exchange::Deribit));
+        assert!(tagged
+            .iter()
+            .any(|(exchange, _)| *exchange == Exchange::Binance));
+        assert!(tagged
+            .iter()
+            .all(|(exchange, instrument)| instrument.id().venue == exchange.as_venue()));
+    }
+
+    /// Returns a router serving the spot and perpetual fixtures according to the type filter.
+    fn typed_router(counter: Arc<AtomicUsize>) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}",
+            get(
+                move |axum::extract::Query(params): axum::extract::Query<
+                    HashMap<String, String>,
+                >| {
+                    let counter = counter.clone();
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        let filter = params.get("filter").cloned().unwrap_or_default();
+                        if filter.contains("\"spot\"") {
+                            format!("[{}]", load_test_json("instrument_spot.json"))
+                        } else if filter.contains("\"perpetual\"") {
+                            format!("[{}]", load_test_json("instrument_perpetual.json"))
+                        } else {
+                            "[]".to_string()
+                        }
+                    }
+                },
+            ),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_count_matches_full_fetch() {
+        let body = format!(
+            "[{},{},{}]",
+            load_test_json("instrument_spot.json"),
+            load_test_json("instrument_perpetual.json"),
+            load_test_json("instrument_future.json"),
+        );
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let body = body.clone();
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let filter = InstrumentFilter {
+            base_currency: Some(vec!["BTC".to_string()]),
+            ..Default::default()
+        };
+
+        let count = client
+            .instruments_count(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+        let instruments = client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(count, instruments.len());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_records() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter)).await;
+        let client = test_client(&base_url);
+
+        let records = client
+            .instruments_records(Exchange::Deribit, None, None, None, None, None, None)
+            .await
+            .unwrap();
+
+        // One record per change window of the spot definition
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].instrument_id, "BTC_USDC.DERIBIT");
+        assert_eq!(records[0].instrument_class, "SPOT");
+        assert_eq!(records[0].base_currency.as_deref(), Some("BTC"));
+        assert_eq!(records[0].quote_currency, "USDC");
+        assert_eq!(records[0].expiry, None);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_symbols() {
+        let body = format!(
+            "[{},{},{}]",
+            load_test_json("instrument_spot.json"),
+            load_test_json("instrument_perpetual.json"),
+            load_test_json("instrument_future.json"),
+        );
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let body = body.clone();
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let symbols = client.symbols(Exchange::Deribit, None, None).await.unwrap();
+
+        assert_eq!(symbols, vec!["BTC_USDC", "XBTUSD", "BTC-14FEB25"]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_info_stream_pages_by_type() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(typed_router(counter.clone())).await;
+        let client = test_client(&base_url);
+
+        let infos: Vec<InstrumentInfo> = client
+            .instruments_info_stream(Exchange::Deribit, None, None, None)
+            .map(|result| result.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(infos.len(), 2);
+        assert_eq!(infos[0].instrument_type, InstrumentType::Spot);
+        assert_eq!(infos[1].instrument_type, InstrumentType::Perpetual);
+        assert_eq!(
+            counter.load(Ordering::SeqCst),
+            InstrumentType::iter().count()
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_info_stream_respects_filter_types() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(typed_router(counter.clone())).await;
+        let client = test_client(&base_url);
+        let filter = InstrumentFilter {
+            instrument_type: Some(vec!["perpetual".to_string()]),
+            ..Default::default()
+        };
+
+        let infos: Vec<InstrumentInfo> = client
+            .instruments_info_stream(Exchange::Deribit, Some(&filter), None, None)
+            .map(|result| result.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].instrument_type, InstrumentType::Perpetual);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_requests_are_routed_through_proxy() {
+        let proxy_auth = Arc::new(std::sync::Mutex::new(None));
+        let proxy_auth_clone = proxy_auth.clone();
+        // Plain HTTP requests are forwarded to the proxy with an absolute URI, so the
+        // mock server can act as the proxy and serve the response directly
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let proxy_auth = proxy_auth_clone.clone();
+                async move {
+                    *proxy_auth.lock().unwrap() = headers
+                        .get("proxy-authorization")
+                        .map(|v| v.to_str().unwrap().to_string());
+                    instruments_json()
+                }
+            }),
+        );
+        let proxy_url = start_mock_server(router).await;
+        let client = test_client("http://api.tardis.invalid")
+            .with_proxy(&proxy_url, Some(("user", "pass")))
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert_eq!(
+            proxy_auth.lock().unwrap().as_deref(),
+            Some("Basic dXNlcjpwYXNz") // user:pass
+        );
+    }
+
+    #[rstest]
+    fn test_with_proxy_invalid_url() {
+        let client = test_client(TARDIS_BASE_URL);
+        assert!(client.with_proxy("not a url", None).is_err());
+    }
+
+    #[rstest]
+    fn test_debug_redacts_api_key() {
+        let client = TardisHttpClient::new(Some("super-secret-key"), None, None, true)
+            .unwrap()
+            .with_proxy("http://127.0.0.1:8080", Some(("user", "proxy-secret")))
+            .unwrap();
+
+        let debug = format!("{client:?}");
+
+        assert!(debug.contains("api_key: \"****\""));
+        assert!(!debug.contains("super-secret-key"));
+        assert!(!debug.contains("proxy-secret"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_info_for_unknown_exchange() {
+        let router = Router::new().route(
+            "/instruments/new-venue",
+            get(|| async {
+                let mut info: serde_json::Value =
+                    serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+                info["exchange"] = "new-venue".into();
+                serde_json::Value::Array(vec![info]).to_string()
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let exchange: Exchange = "new-venue".parse().unwrap();
+
+        let infos = client
+            .instruments_info(exchange.clone(), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(infos[0].exchange, exchange);
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(false, true)]
+    #[case(true, false)]
+    #[case(true, true)]
+    #[tokio::test]
+    async fn test_response_too_large(#[case] chunked: bool, #[case] buffered: bool) {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || async move {
+                let body = instruments_json();
+                if chunked {
+                    // Streamed without a `Content-Length`, so the guard applies while reading
+                    let chunks = body
+                        .into_bytes()
+                        .chunks(64)
+                        .map(|chunk| Ok::<_, std::io::Error>(chunk.to_vec()))
+                        .collect::<Vec<_>>();
+                    axum::body::Body::from_stream(stream::iter(chunks)).into_response()
+                } else {
+                    body.into_response()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .buffer_responses(buffered)
+            .max_response_bytes(256)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert!(
+            matches!(result, Err(Error::ResponseTooLarge { limit: 256 })),
+            "{result:?}"
+        );
+    }
+
+    #[rstest]
+    #[case(false, "")]
+    #[case(true, "")]
+    #[case(false, " \n")]
+    #[case(true, " \n")]
+    #[tokio::test]
+    async fn test_empty_response_body(#[case] buffered: bool, #[case] body: &'static str) {
+        let router = Router::new()
+            .route("/instruments/{exchange}", get(move || async move { body }))
+            .route(
+                "/instruments/{exchange}/{symbol}",
+                get(move || async move { body }),
+            );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .buffer_responses(buffered)
+            .build()
+            .unwrap();
+
+        let instruments = client.instruments_info(Exchange::Deribit, None, None).await;
+        let instrument = client
+            .instrument_info(Exchange::Deribit, "BTC-PERPETUAL", None)
+            .await;
+
+        assert!(matches!(
+            instruments,
+            Err(Error::EmptyResponse { status: 200 })
+        ));
+        assert!(matches!(
+            instrument,
+            Err(Error::EmptyResponse { status: 200 })
+        ));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_decodes_gzip_response() {
+        use std::io::Write;
+
+        use flate2::{write::GzEncoder, Compression};
+
+        let accept_encoding = Arc::new(std::sync::Mutex::new(String::new()));
+        let accept_encoding_clone = accept_encoding.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let accept_encoding = accept_encoding_clone.clone();
+                async move {
+                    *accept_encoding.lock().unwrap() = headers
+                        .get("accept-encoding")
+                        .map(|v| v.to_str().unwrap().to_string())
+                        .unwrap_or_default();
+
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                    encoder.write_all(instruments_json().as_bytes()).unwrap();
+                    ([("content-encoding", "gzip")], encoder.finish().unwrap())
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let infos = client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(infos.len(), 1);
+        let accept_encoding = accept_encoding.lock().unwrap();
+        assert!(accept_encoding.contains("gzip"));
+        assert!(accept_encoding.contains("br"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_rate_limited_uses_retry_after() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let counter = counter_clone.clone();
+                async move {
+                    if counter.fetch_add(1, Ordering::SeqCst) == 0 {
+                        (StatusCode::TOO_MANY_REQUESTS, [("retry-after", "1")], "").into_response()
+                    } else {
+                        (StatusCode::OK, instruments_json()).into_response()
+                    }
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url).with_retries(
+            1,
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+        );
+
+        let start = Instant::now();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    #[tokio::test]
+    async fn test_service_unavailable_with_retry_after(#[case] http_date: bool) {
+        let retry_after = if http_date {
+            (chrono::Utc::now() + chrono::Duration::seconds(120)).to_rfc2822()
+        } else {
+            "120".to_string()
+        };
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let retry_after = retry_after.clone();
+                async move {
+                    (
+                        StatusCode::SERVICE_UNAVAILABLE,
+                        [("retry-after", retry_after)],
+                        "Scheduled maintenance",
+                    )
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ServiceUnavailable {
+                retry_after,
+                message,
+                ..
+            }) => {
+                assert!(
+                    retry_after > Duration::from_secs(118)
+                        && retry_after <= Duration::from_secs(120)
+                );
+                assert_eq!(message, "Scheduled maintenance");
+            }
+            other => panic!("Expected `ServiceUnavailable`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_not_found_error() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url =
+            start_mock_server(flaky_router(StatusCode::NOT_FOUND, 1, counter.clone())).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::NotFound { message, .. }) => assert_eq!(message, "Unavailable"),
+            other => panic!("Expected `NotFound`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_per_call_timeout() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async {
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                instruments_json()
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client
+            .instruments_info(Exchange::Deribit, None, Some(Duration::from_millis(1)))
+            .await;
+
+        match result {
+            Err(Error::Request(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected timeout error, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_builder_defaults() {
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url, TARDIS_BASE_URL);
+        assert_eq!(client.timeout, Duration::from_secs(60));
+        assert!(client.normalize_symbols);
+        assert!(client.retry_config.is_none());
+        assert!(client.state.instruments_cache.is_none());
+    }
+
+    #[rstest]
+    fn test_builder_options() {
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url("http://127.0.0.1:8080")
+            .timeout(Duration::from_secs(5))
+            .normalize_symbols(false)
+            .retries(3, Duration::from_millis(10), Duration::from_millis(50))
+            .cache_ttl(Duration::from_secs(60))
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(Duration::from_secs(30))
+            .tcp_keepalive(Duration::from_secs(15))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url, "http://127.0.0.1:8080");
+        assert_eq!(client.timeout, Duration::from_secs(5));
+        assert!(!client.normalize_symbols);
+        assert_eq!(client.retry_config.unwrap().max_retries, 3);
+        assert!(client.state.instruments_cache.is_some());
+        assert_eq!(client.pool_config.max_idle_per_host, Some(4));
+        assert_eq!(
+            client.pool_config.idle_timeout,
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            client.pool_config.tcp_keepalive,
+            Some(Duration::from_secs(15))
+        );
+    }
+
+    #[rstest]
+    fn test_builder_invalid_proxy() {
+        let result = TardisHttpClient::builder()
+            .api_key("test-key")
+            .proxy("not a url", None)
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_from_saved_file() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url);
+        let path =
+            env::temp_dir().join(format!("tardis-instruments-{}.json", uuid::Uuid::new_v4()));
+
+        client
+            .save_instruments_info(Exchange::Deribit, None, &path, None)
+            .await
+            .unwrap();
+        let online = client
+            .instruments(
+                Exchange::Deribit,
+                None,
+                None,
+                Some(0),
+                None,
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap();
+        let offline = client
+            .instruments_from_file(&path, Exchange::Deribit, None, None, Some(0))
+            .unwrap();
+        let other_exchange = client
+            .instruments_from_file(&path, Exchange::Bitmex, None, None, Some(0))
+            .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(offline, online);
+        assert!(other_exchange.is_empty());
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_to_parquet() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url);
+        let path = env::temp_dir().join(format!(
+            "tardis-instruments-{}.parquet",
+            uuid::Uuid::new_v4()
+        ));
+
+        let written = client
+            .instruments_to_parquet(&[Exchange::Deribit, Exchange::Bitmex], &path, None)
+            .await
+            .unwrap();
+
+        let file = fs::File::open(&path).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 2);
+        assert_eq!(batches[0].schema().fields(), instruments_schema().fields());
+    }
+
+    #[rstest]
+    fn test_instruments_from_missing_file() {
+        let client = test_client(TARDIS_BASE_URL);
+        let path = env::temp_dir().join("tardis-instruments-missing.json");
+
+        let result = client.instruments_from_file(&path, Exchange::Deribit, None, None, None);
+
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_custom_user_agent_and_default_headers() {
+        let received = Arc::new(std::sync::Mutex::new(axum::http::HeaderMap::new()));
+        let received_clone = received.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let received = received_clone.clone();
+                async move {
+                    *received.lock().unwrap() = headers;
+                    instruments_json()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(base_url)
+            .user_agent("my-service/1.0")
+            .default_headers(HashMap::from([(
+                "x-correlation-id".to_string(),
+                "abc-123".to_string(),
+            )]))
+            .build()
+            .unwrap();
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.get("user-agent").unwrap(), "my-service/1.0");
+        assert_eq!(received.get("x-correlation-id").unwrap(), "abc-123");
+        assert_eq!(received.get("authorization").unwrap(), "Bearer test-key");
+    }
+
+    #[rstest]
+    #[case("invalid header", "value")]
+    #[case("x-valid", "invalid\nvalue")]
+    fn test_builder_invalid_default_header(#[case] name: &str, #[case] value: &str) {
+        let result = TardisHttpClient::builder()
+            .api_key("test-key")
+            .default_headers(HashMap::from([(name.to_string(), value.to_string())]))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(AuthStyle::Bearer, "authorization", "Bearer test-key")]
+    #[case(AuthStyle::Header("x-api-key".to_string()), "x-api-key", "test-key")]
+    #[tokio::test]
+    async fn test_auth_style(
+        #[case] auth_style: AuthStyle,
+        #[case] header: &str,
+        #[case] expected: &str,
+    ) {
+        let received = Arc::new(std::sync::Mutex::new(axum::http::HeaderMap::new()));
+        let received_clone = received.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let received = received_clone.clone();
+                async move {
+                    *received.lock().unwrap() = headers;
+                    instruments_json()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .auth_style(auth_style.clone())
+            .base_url(base_url)
+            .build()
+            .unwrap();
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.get(header).unwrap(), expected);
+        if auth_style != AuthStyle::Bearer {
+            assert!(received.get("authorization").is_none());
+        }
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("2024-01-01"))]
+    #[tokio::test]
+    async fn test_api_version_header(#[case] api_version: Option<&str>) {
+        let received = Arc::new(std::sync::Mutex::new(axum::http::HeaderMap::new()));
+        let received_clone = received.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let received = received_clone.clone();
+                async move {
+                    *received.lock().unwrap() = headers;
+                    instruments_json()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let mut builder = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(base_url);
+        if let Some(api_version) = api_version {
+            builder = builder.api_version(api_version);
+        }
+        let client = builder.build().unwrap();
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(
+            received
+                .get(HEADER_API_VERSION)
+                .map(|value| value.to_str().unwrap()),
+            api_version
+        );
+    }
+
+    #[rstest]
+    fn test_builder_invalid_auth_header() {
+        let result = TardisHttpClient::builder()
+            .api_key("test-key")
+            .auth_style(AuthStyle::Header("invalid header".to_string()))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_exchanges() {
+        let router = Router::new().route(
+            "/exchanges",
+            get(|| async { load_test_json("exchanges.json") }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let exchanges = client.exchanges(None).await.unwrap();
+
+        assert_eq!(exchanges.len(), 3);
+        assert_eq!(exchanges[0].id, Exchange::Bitmex);
+        assert_eq!(exchanges[0].name, "BitMEX");
+        assert!(exchanges[0].enabled);
+        assert!(exchanges[0]
+            .available_channels
+            .contains(&"trade".to_string()));
+        assert!(exchanges[0].data_types.contains(&"trades".to_string()));
+        assert_eq!(exchanges[1].delisted, Some(true));
+        assert!(exchanges[1].available_to.is_some());
+        assert_eq!(exchanges[2].id, Exchange::Other("new-venue".to_string()));
+        assert!(!exchanges[2].supports_datasets);
+        assert!(exchanges[2].available_channels.is_empty());
+    }
+
+    #[rstest]
+    #[case(StatusCode::BAD_REQUEST)]
+    #[case(StatusCode::UNAUTHORIZED)]
+    #[case(StatusCode::INTERNAL_SERVER_ERROR)]
+    #[tokio::test]
+    async fn test_custom_error_parser(#[case] status: StatusCode) {
+        #[derive(Deserialize)]
+        struct GatewayError {
+            id: u64,
+            reason: String,
+        }
+
+        impl From<GatewayError> for (u64, String) {
+            fn from(error: GatewayError) -> Self {
+                (error.id, error.reason)
+            }
+        }
+
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || async move {
+                (status, r#"{"id":42,"reason":"Unknown dataset"}"#).into_response()
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .error_parser(Arc::new(JsonErrorParser::<GatewayError>::new()))
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        let (code, message) = match (status, result) {
+            (StatusCode::BAD_REQUEST, Err(Error::ApiError { code, message, .. }))
+            | (StatusCode::UNAUTHORIZED, Err(Error::Unauthorized { code, message, .. }))
+            | (StatusCode::INTERNAL_SERVER_ERROR, Err(Error::ServerError { code, message, .. })) => {
+                (code, message)
+            }
+            (_, other) => panic!("Unexpected result for {status}: {other:?}"),
+        };
+        assert_eq!(code, 42);
+        assert_eq!(message, "Unknown dataset");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_get_raw() {
+        let router =
+            Router::new().route(
+                "/exchanges/{exchange}",
+                get(
+                    |headers: axum::http::HeaderMap,
+                     axum::extract::Query(params): axum::extract::Query<
+                        HashMap<String, String>,
+                    >| async move {
+                        let auth = headers
+                            .get("authorization")
+                            .and_then(|v| v.to_str().ok())
+                            .unwrap_or_default()
+                            .to_string();
+                        axum::Json(serde_json::json!({ "auth": auth, "params": params }))
+                    },
+                ),
+            );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let query = [("from".to_string(), "2025-01-01 00:00".to_string())];
+
+        let value = client
+            .get_raw("/exchanges/deribit", Some(&query))
+            .await
+            .unwrap();
+
+        assert_eq!(value["auth"], "Bearer test-key");
+        assert_eq!(value["params"]["from"], "2025-01-01 00:00");
+
+        let result = client.get_raw("/missing", None).await;
+
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    #[rstest]
+    #[case(Exchange::Bitmex, "incremental_book_L2", true)]
+    #[case(Exchange::Bitmex, "orderBookL2", true)]
+    #[case(Exchange::Bitmex, "book_snapshot_25", false)]
+    #[case(Exchange::Ftx, "trades", true)]
+    #[case(Exchange::Other("new-venue".to_string()), "trades", false)]
+    #[tokio::test]
+    async fn test_exchange_supports(
+        #[case] exchange: Exchange,
+        #[case] data_type: &str,
+        #[case] expected: bool,
+    ) {
+        let router = Router::new().route(
+            "/exchanges",
+            get(|| async { load_test_json("exchanges.json") }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let supported = client
+            .exchange_supports(&exchange, data_type, None)
+            .await
+            .unwrap();
+
+        assert_eq!(supported, expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_exchange_supports_unknown_exchange() {
+        let router = Router::new().route(
+            "/exchanges",
+            get(|| async { load_test_json("exchanges.json") }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client
+            .exchange_supports(&Exchange::Deribit, "trades", None)
+            .await;
+
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    /// Returns a Binance Futures perpetual, for which normalization appends a `-PERP` suffix.
+    fn binance_perpetual_json() -> String {
+        let mut info: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        info["id"] = "BTCUSDT".into();
+        info["exchange"] = "binance-futures".into();
+        info.to_string()
+    }
+
+    /// Returns a router serving the Binance Futures perpetual for both instrument endpoints.
+    fn perpetual_router() -> Router {
+        Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(|| async { format!("[{}]", binance_perpetual_json()) }),
+            )
+            .route(
+                "/instruments/{exchange}/{symbol}",
+                get(|| async { binance_perpetual_json() }),
+            )
+    }
+
+    /// Prefixes uppercased raw symbols with a firm identifier.
+    struct PrefixNormalizer;
+
+    impl SymbolNormalizer for PrefixNormalizer {
+        fn normalize(
+            &self,
+            raw: &str,
+            _exchange: &Exchange,
+            _instrument_type: &InstrumentType,
+            _is_inverse: Option<bool>,
+        ) -> Symbol {
+            Symbol::new(format!("ACME-{}", raw.to_uppercase()))
+        }
+    }
+
+    #[rstest]
+    #[case(None, "ACME-BTCUSDT.BINANCE")]
+    #[case(Some(false), "BTCUSDT.BINANCE")]
+    #[tokio::test]
+    async fn test_instruments_custom_symbol_normalizer(
+        #[case] normalize_symbols: Option<bool>,
+        #[case] expected: &str,
+    ) {
+        let base_url = start_mock_server(perpetual_router()).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .symbol_normalizer(Arc::new(PrefixNormalizer))
+            .build()
+            .unwrap();
+
+        let instruments = client
+            .instruments(
+                Exchange::BinanceFutures,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions {
+                    normalize_symbols,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(instruments[0].id(), InstrumentId::from(expected));
+        assert_eq!(instruments[0].raw_symbol(), Symbol::from("BTCUSDT"));
+    }
+
+    #[rstest]
+    #[case(None, "BTCUSDT.BINANCE")]
+    #[case(Some(true), "BTCUSDT-PERP.BINANCE")]
+    #[case(Some(false), "BTCUSDT.BINANCE")]
+    #[tokio::test]
+    async fn test_instruments_normalize_symbols_override(
+        #[case] normalize_symbols: Option<bool>,
+        #[case] expected: &str,
+    ) {
+        let base_url = start_mock_server(perpetual_router()).await;
+        let client = test_client(&base_url); // Client does not normalize symbols
+
+        let instruments = client
+            .instruments(
+                Exchange::BinanceFutures,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions {
+                    normalize_symbols,
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(instruments[0].id().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(None, "BTCUSDT-PERP.BINANCE")]
+    #[case(Some(false), "BTCUSDT.BINANCE")]
+    #[case(Some(true), "BTCUSDT-PERP.BINANCE")]
+    #[tokio::test]
+    async fn test_instrument_normalize_symbols_override(
+        #[case] normalize_symbols: Option<bool>,
+        #[case] expected: &str,
+    ) {
+        let base_url = start_mock_server(perpetual_router()).await;
+        let client = TardisHttpClient::new(Some("test-key"), Some(&base_url), None, true).unwrap();
+
+        let instruments = TardisHttpClient::instrument(
+            &client,
+            Exchange::BinanceFutures,
+            "BTCUSDT",
+            None,
+            None,
+            None,
+            &InstrumentOptions {
+                normalize_symbols,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(instruments[0].id().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(InstrumentClass::Swap, 1)]
+    #[case(InstrumentClass::Spot, 0)]
+    #[tokio::test]
+    async fn test_instrument_filtered_by_class(
+        #[case] class: InstrumentClass,
+        #[case] expected_len: usize,
+    ) {
+        let base_url = start_mock_server(perpetual_router()).await;
+        let client = test_client(&base_url);
+        let options = InstrumentOptions {
+            classes: Some(vec![class]),
+            ..Default::default()
+        };
+
+        let instruments = TardisHttpClient::instrument(
+            &client,
+            Exchange::BinanceFutures,
+            "BTCUSDT",
+            None,
+            None,
+            None,
+            &options,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(instruments.len(), expected_len);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_response_parse_error_includes_body_snippet() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { "[{\"id\": \"BTC_USDC\", \"note\": \"test-key\"" }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .buffer_responses(true)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse {
+                status,
+                body_snippet,
+                ..
+            }) => {
+                assert_eq!(status, 200);
+                assert!(body_snippet.starts_with("[{\"id\": \"BTC_USDC\""));
+                assert!(!body_snippet.contains("test-key"));
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_buffered_response_matches_streamed() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { format!("[{}]", load_test_json("instrument_perpetual_changes.json")) }),
+        );
+        let base_url = start_mock_server(router).await;
+        let buffered = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .buffer_responses(true)
+            .build()
+            .unwrap();
+
+        let expected = test_client(&base_url)
+            .instruments_info(Exchange::Bitmex, None, None)
+            .await
+            .unwrap();
+        let infos = buffered
+            .instruments_info(Exchange::Bitmex, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(format!("{infos:?}"), format!("{expected:?}"));
+    }
+
+    #[rstest]
+    #[traced_test]
+    #[tokio::test]
+    async fn test_buffered_response_invalid_utf8() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { b"[{\"id\": \"BTC\xff\"}]".to_vec() }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .buffer_responses(true)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse { body_snippet, .. }) => {
+                assert!(body_snippet.contains("BTC\u{fffd}"));
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+        assert!(logs_contain("Response body was"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_streamed_response_parse_error() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { "[{\"id\": \"BTC_USDC\", \"note\": \"test-key\"" }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse {
+                status,
+                body_snippet,
+                ..
+            }) => {
+                assert_eq!(status, 200);
+                assert!(body_snippet.starts_with("[{\"id\": \"BTC_USDC\""));
+                assert!(!body_snippet.contains("test-key"));
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::streamed(false)]
+    #[case::buffered(true)]
+    #[tokio::test]
+    async fn test_response_parse_error_masks_provided_key(#[case] buffered: bool) {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { "[{\"id\": \"BTC_USDC\", \"note\": \"provided-key\"" }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key_provider(|| Ok("provided-key".to_string()))
+            .base_url(&base_url)
+            .buffer_responses(buffered)
+            .build()
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse { body_snippet, .. }) => {
+                assert!(body_snippet.starts_with("[{\"id\": \"BTC_USDC\""));
+                assert!(!body_snippet.contains("provided-key"), "{body_snippet}");
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_streamed_response_parse_error_truncates_snippet() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { format!("[{}{{", "\"padding\",".repeat(1_000)) }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse { body_snippet, .. }) => {
+                assert!(body_snippet.starts_with("[\"padding\","));
+                assert!(body_snippet.ends_with("..."));
+                assert_eq!(body_snippet.len(), RESPONSE_SNIPPET_MAX_LEN + 3);
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case::crossing_snippet_end(format!("[\"{}test-key{}", "x".repeat(506), "x".repeat(1_000)))]
+    #[case::crossing_prefix_end(format!(
+        "[\"test-keytest-key{}test-key{}",
+        "x".repeat(500),
+        "x".repeat(1_000)
+    ))]
+    #[tokio::test]
+    async fn test_streamed_response_parse_error_masks_truncated_key(#[case] body: String) {
+        let router = Router::new().route("/instruments/{exchange}", get(move || async { body }));
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        match result {
+            Err(Error::ResponseParse { body_snippet, .. }) => {
+                assert!(body_snippet.ends_with("..."));
+                assert!(!body_snippet.contains('t'), "{body_snippet}");
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_pool_options_preserved_with_proxy() {
+        let proxy_url = start_mock_server(flaky_router(
+            StatusCode::OK,
+            0,
+            Arc::new(AtomicUsize::new(0)),
+        ))
+        .await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url("http://api.tardis.invalid")
+            .pool_max_idle_per_host(1)
+            .pool_idle_timeout(Duration::from_secs(5))
+            .tcp_keepalive(Duration::from_secs(30))
+            .build()
+            .unwrap()
+            .with_proxy(&proxy_url, None)
+            .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert_eq!(client.pool_config.max_idle_per_host, Some(1));
+    }
+
+    #[rstest]
+    #[case(StatusCode::OK, true)]
+    #[case(StatusCode::UNAUTHORIZED, false)]
+    #[tokio::test]
+    async fn test_ping(#[case] status: StatusCode, #[case] expected_ok: bool) {
+        let router = Router::new().route(
+            "/api-key-info",
+            get(move || async move { (status, "[]").into_response() }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let result = client.ping(None).await;
+
+        if expected_ok {
+            assert!(result.is_ok());
+        } else {
+            assert!(matches!(
+                result,
+                Err(Error::Unauthorized { status: 401, .. })
+            ));
+        }
+    }
+
+    /// Returns a router serving a perpetual for each symbol, except `MISSING` (a 404).
+    fn symbol_router(counter: Arc<AtomicUsize>) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}/{symbol}",
+            get(
+                move |axum::extract::Path((_, symbol)): axum::extract::Path<(String, String)>| {
+                    let counter = counter.clone();
+                    async move {
+                        counter.fetch_add(1, Ordering::SeqCst);
+                        if symbol == "MISSING" {
+                            return (
+                                StatusCode::NOT_FOUND,
+                                "{\"code\":404,\"message\":\"Instrument not found\"}".to_string(),
+                            )
+                                .into_response();
+                        }
+                        let mut info: serde_json::Value =
+                            serde_json::from_str(&load_test_json("instrument_perpetual.json"))
+                                .unwrap();
+                        info["id"] = symbol.into();
+                        info["exchange"] = "binance-futures".into();
+                        (StatusCode::OK, info.to_string()).into_response()
+                    }
+                },
+            ),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instrument_by_id_maps_venue_and_symbol() {
+        let requested = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let requested_clone = requested.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}/{symbol}",
+            get(
+                move |axum::extract::Path((exchange, symbol)): axum::extract::Path<(
+                    String,
+                    String,
+                )>| {
+                    let requested = requested_clone.clone();
+                    async move {
+                        requested
+                            .lock()
+                            .unwrap()
+                            .push(format!("{exchange}/{symbol}"));
+                        if exchange != "binance-futures" {
+                            return (
+                                StatusCode::NOT_FOUND,
+                                "{\"code\":404,\"message\":\"Instrument not found\"}".to_string(),
+                            )
+                                .into_response();
+                        }
+                        let mut info: serde_json::Value =
+                            serde_json::from_str(&load_test_json("instrument_perpetual.json"))
+                                .unwrap();
+                        info["id"] = symbol.into();
+                        info["exchange"] = exchange.into();
+                        (StatusCode::OK, info.to_string()).into_response()
+                    }
+                },
+            ),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::new(Some("test-key"), Some(&base_url), None, true).unwrap();
+        let instrument_id = InstrumentId::from("BTCUSDT-PERP.BINANCE");
+
+        let instruments = client
+            .instrument_by_id(&instrument_id, None, None, None, None)
+            .await
+            .unwrap();
+
+        assert!(!instruments.is_empty());
+        assert!(instruments
+            .iter()
+            .all(|instrument| instrument.id() == instrument_id));
+        assert_eq!(
+            requested.lock().unwrap().last().unwrap(),
+            "binance-futures/BTCUSDT"
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instrument_by_id_not_found() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(instrument_router(counter)).await;
+        let client = test_client(&base_url);
+
+        let result = client
+            .instrument_by_id(
+                &InstrumentId::from("MISSING.DERIBIT"),
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_by_symbols() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(symbol_router(counter.clone())).await;
+        let client = test_client(&base_url);
+        let symbols: Vec<String> = ["BTCUSDT", "ETHUSDT", "SOLUSDT", "XRPUSDT"]
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let instruments = client
+            .instruments_by_symbols(
+                Exchange::BinanceFutures,
+                &symbols,
+                2,
+                None,
+                None,
+                None,
+                Some(true),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = instruments.iter().map(|i| i.id().to_string()).collect();
+        assert_eq!(
+            ids,
+            vec![
+                "BTCUSDT-PERP.BINANCE",
+                "ETHUSDT-PERP.BINANCE",
+                "SOLUSDT-PERP.BINANCE",
+                "XRPUSDT-PERP.BINANCE",
+            ]
+        );
+        assert_eq!(counter.load(Ordering::SeqCst), 4);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_by_symbols_reports_failed_symbol() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(symbol_router(counter)).await;
+        let client = test_client(&base_url);
+        let symbols = vec!["BTCUSDT".to_string(), "MISSING".to_string()];
+
+        let result = client
+            .instruments_by_symbols(
+                Exchange::BinanceFutures,
+                &symbols,
+                2,
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+        match result {
+            Err(Error::Symbol { symbol, source }) => {
+                assert_eq!(symbol, "MISSING");
+                assert!(matches!(*source, Error::NotFound { .. }));
+            }
+            other => panic!("Expected `Symbol` error, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("2024-01-01T00:00:00Z", vec!["BTC_USDC.DERIBIT", "BTC-14FEB25.DERIBIT"])]
+    #[case("2024-06-01T00:00:00Z", vec!["BTC-14FEB25.DERIBIT"])]
+    #[case("2025-06-01T00:00:00Z", vec![])]
+    #[tokio::test]
+    async fn test_instruments_changed_since(#[case] since: &str, #[case] expected: Vec<&str>) {
+        let body = format!(
+            "[{},{},{}]",
+            load_test_json("instrument_spot.json"),
+            load_test_json("instrument_perpetual.json"),
+            load_test_json("instrument_future.json"),
+        );
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let body = body.clone();
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let since = UnixNanos::from(
+            chrono::DateTime::parse_from_rfc3339(since)
+                .unwrap()
+                .timestamp_nanos_opt()
+                .unwrap() as u64,
+        );
+
+        let instruments = client
+            .instruments_changed_since(Exchange::Deribit, since, None, None, None, None)
+            .await
+            .unwrap();
+
+        let mut instrument_ids: Vec<String> = instruments
+            .iter()
+            .map(|instrument| instrument.id().to_string())
+            .collect();
+        instrument_ids.dedup();
+        assert_eq!(instrument_ids, expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_by_base_currency() {
+        let mut eth_spot: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+        eth_spot["id"] = "ETH_USDC".into();
+        eth_spot["baseCurrency"] = "ETH".into();
+        let body = format!(
+            "[{},{},{eth_spot}]",
+            load_test_json("instrument_spot.json"),
+            load_test_json("instrument_option.json"),
+        );
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let body = body.clone();
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let total = client
+            .instruments(
+                Exchange::Deribit,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap()
+            .len();
+        let grouped = client
+            .instruments_by_base_currency(Exchange::Deribit, None, None, None, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(grouped.len(), 2);
+        assert!(grouped[&Currency::ETH()]
+            .iter()
+            .all(|instrument| instrument.raw_symbol().as_str() == "ETH_USDC"));
+        assert!(grouped[&Currency::BTC()]
+            .iter()
+            .all(|instrument| instrument.raw_symbol().as_str() != "ETH_USDC"));
+        assert!(grouped[&Currency::BTC()]
+            .iter()
+            .any(|instrument| matches!(instrument, InstrumentAny::OptionContract(_))));
+        assert_eq!(grouped.values().map(Vec::len).sum::<usize>(), total);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_filtered_by_symbols() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router =
+            Router::new()
+                .route(
+                    "/instruments/{exchange}",
+                    get(move || {
+                        let counter = counter_clone.clone();
+                        async move {
+                            counter.fetch_add(1, Ordering::SeqCst);
+                            "[]"
+                        }
+                    }),
+                )
+                .route(
+                    "/instruments/{exchange}/{symbol}",
+                    get(
+                        |axum::extract::Path((_, symbol)): axum::extract::Path<(
+                            String,
+                            String,
+                        )>| async move {
+                            let mut info: serde_json::Value =
+                                serde_json::from_str(&load_test_json("instrument_perpetual.json"))
+                                    .unwrap();
+                            info["id"] = symbol.into();
+                            info["exchange"] = "binance-futures".into();
+                            info.to_string()
+                        },
+                    ),
+                );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let filter = InstrumentFilter {
+            symbols: Some(vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()]),
+            ..Default::default()
+        };
+
+        let instruments = client
+            .instruments(
+                Exchange::BinanceFutures,
+                None,
+                None,
+                None,
+                Some(&filter),
+                &InstrumentOptions {
+                    normalize_symbols: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = instruments.iter().map(|i| i.id().to_string()).collect();
+        assert_eq!(ids, vec!["BTCUSDT-PERP.BINANCE", "ETHUSDT-PERP.BINANCE"]);
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_empty_symbols_filter_requests_all_instruments() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url);
+        let filter = InstrumentFilter {
+            symbols: Some(vec![]),
+            ..Default::default()
+        };
+
+        let infos = client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+        let count = client
+            .instruments_count(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+        let symbols = client
+            .symbols(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(count, 1);
+        assert_eq!(symbols, vec!["BTC_USDC"]);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+
+    #[derive(Default)]
+    struct CountingMetrics {
+        requests: AtomicUsize,
+        responses: std::sync::Mutex<Vec<(String, u16)>>,
+        errors: AtomicUsize,
+    }
+
+    impl TardisMetrics for CountingMetrics {
+        fn on_request(&self, _endpoint: &str) {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_response(&self, endpoint: &str, status: u16, _elapsed: Duration) {
+            self.responses
+                .lock()
+                .unwrap()
+                .push((endpoint.to_string(), status));
+        }
+
+        fn on_error(&self, _endpoint: &str) {
+            self.errors.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_metrics_hooks() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(
+            StatusCode::SERVICE_UNAVAILABLE,
+            1,
+            counter.clone(),
+        ))
+        .await;
+        let metrics = Arc::new(CountingMetrics::default());
+        let client = test_client(&base_url)
+            .with_retries(1, Duration::from_millis(10), Duration::from_millis(10))
+            .with_metrics(metrics.clone());
+        let filter = InstrumentFilter {
+            active: ActiveFilter::Active,
+            ..Default::default()
+        };
+
+        client
+            .instruments_info(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(metrics.requests.load(Ordering::SeqCst), 2);
+        assert_eq!(metrics.errors.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            *metrics.responses.lock().unwrap(),
+            vec![
+                ("/instruments/deribit".to_string(), 503),
+                ("/instruments/deribit".to_string(), 200),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_with_prebuilt_client() {
+        let user_agent = Arc::new(std::sync::Mutex::new(String::new()));
+        let user_agent_clone = user_agent.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move |headers: axum::http::HeaderMap| {
+                let user_agent = user_agent_clone.clone();
+                async move {
+                    *user_agent.lock().unwrap() = headers
+                        .get("user-agent")
+                        .map(|v| v.to_str().unwrap().to_string())
+                        .unwrap_or_default();
+                    instruments_json()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let shared_client = reqwest::Client::builder()
+            .user_agent("shared-client")
+            .build()
+            .unwrap();
+        let client =
+            TardisHttpClient::with_client(shared_client, Some("test-key"), Some(&base_url), true)
+                .unwrap();
+
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+        assert_eq!(*user_agent.lock().unwrap(), "shared-client");
+    }
+
+    /// Returns a router which responds to instrument requests after `delay`.
+    fn slow_router(delay: Duration) -> Router {
+        Router::new().route(
+            "/instruments/{exchange}",
+            get(move || async move {
+                tokio::time::sleep(delay).await;
+                instruments_json()
+            }),
+        )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_slow_request_warns() {
+        let base_url = start_mock_server(slow_router(Duration::from_millis(150))).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .slow_request_threshold(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert!(logs_contain("Slow request to /instruments/deribit"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_fast_request_does_not_warn() {
+        let base_url = start_mock_server(slow_router(Duration::ZERO)).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .slow_request_threshold(Duration::from_millis(100))
+            .build()
+            .unwrap();
+
+        client
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert!(!logs_contain("Slow request to /instruments/deribit"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_multi_cancelled() {
+        let base_url = start_mock_server(slow_router(Duration::from_secs(5))).await;
+        let client = test_client(&base_url);
+        let cancellation = CancellationToken::new();
+        let cancellation_clone = cancellation.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancellation_clone.cancel();
+        });
+
+        let start = Instant::now();
+        let results = client
+            .instruments_multi(
+                &[Exchange::Deribit, Exchange::Bitmex],
+                None,
+                None,
+                None,
+                None,
+                1,
+                Some(cancellation),
+                None,
+            )
+            .await;
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .values()
+            .all(|result| matches!(result, Err(Error::Cancelled))));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_deadline_exceeded_by_slow_response() {
+        let base_url = start_mock_server(slow_router(Duration::from_secs(5))).await;
+        let client = test_client(&base_url)
+            .with_retries(3, Duration::from_millis(10), Duration::from_millis(50))
+            .with_deadline(Instant::now() + Duration::from_millis(100));
+
+        let start = Instant::now();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        assert!(matches!(result, Err(Error::DeadlineExceeded)));
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_deadline_stops_retries() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(
+            StatusCode::SERVICE_UNAVAILABLE,
+            10,
+            counter.clone(),
+        ))
+        .await;
+        let client = test_client(&base_url)
+            .with_retries(10, Duration::from_secs(1), Duration::from_secs(1))
+            .with_deadline(Instant::now() + Duration::from_millis(500));
+
+        let start = Instant::now();
+        let result = client.instruments_info(Exchange::Deribit, None, None).await;
+
+        // The first retry delay would end after the deadline, so fails without waiting
+        assert!(matches!(result, Err(Error::DeadlineExceeded)));
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    /// Returns a linear (USDT-settled) and an inverse (BTC-settled) perpetual.
+    fn settlement_router() -> Router {
+        let mut linear: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        linear["id"] = "XBTUSDT".into();
+        linear["quoteCurrency"] = "USDT".into();
+        linear["inverse"] = false.into();
+        linear["contractType"] = "linear_perpetual".into();
+
+        let mut inverse: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        inverse["settlementCurrency"] = "BTC".into();
+
+        let body = format!("[{linear},{inverse}]");
+        Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let body = body.clone();
+                async move { body }
+            }),
+        )
+    }
+
+    #[rstest]
+    #[case("usdt", "XBTUSDT.BITMEX")]
+    #[case("BTC", "XBTUSD.BITMEX")]
+    #[tokio::test]
+    async fn test_instruments_settlement_currency_filter(
+        #[case] settlement_currency: &str,
+        #[case] expected_id: &str,
+    ) {
+        let base_url = start_mock_server(settlement_router()).await;
+        let client = test_client(&base_url);
+        let filter = InstrumentFilter {
+            settlement_currency: Some(vec![settlement_currency.to_string()]),
+            ..Default::default()
+        };
+
+        let instruments = client
+            .instruments(
+                Exchange::Bitmex,
+                None,
+                None,
+                None,
+                Some(&filter),
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap();
+        let streamed: Vec<InstrumentAny> = client
+            .instruments_stream(
+                Exchange::Bitmex,
+                None,
+                None,
+                None,
+                Some(&filter),
+                None,
+                None,
+            )
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(instruments.len(), 1);
+        assert_eq!(instruments[0].id().to_string(), expected_id);
+        assert_eq!(streamed, instruments);
+    }
+
+    /// Maps alias listings with and without a `-PERP` suffix to the same symbol.
+    struct AliasNormalizer;
+
+    impl SymbolNormalizer for AliasNormalizer {
+        fn normalize(
+            &self,
+            raw: &str,
+            _exchange: &Exchange,
+            _instrument_type: &InstrumentType,
+            _is_inverse: Option<bool>,
+        ) -> Symbol {
+            Symbol::new(format!("{}-PERP", raw.trim_end_matches("-PERP")))
+        }
+    }
+
+    #[traced_test]
+    #[rstest]
+    #[case(false, 3)]
+    #[case(true, 1)]
+    #[tokio::test]
+    async fn test_instruments_dedup_aliases(#[case] dedup: bool, #[case] expected_len: usize) {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { load_test_json("instrument_perpetual_aliases.json") }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .base_url(&base_url)
+            .symbol_normalizer(Arc::new(AliasNormalizer))
+            .dedup_instruments(dedup)
+            .build()
+            .unwrap();
+
+        let instruments = client
+            .instruments(
+                Exchange::BinanceFutures,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(instruments.len(), expected_len);
+        assert!(instruments
+            .iter()
+            .all(|i| i.id().to_string() == "BTCUSDT-PERP.BINANCE"));
+        if dedup {
+            assert_eq!(instruments[0].raw_symbol().as_str(), "BTCUSDT-PERP");
+            assert!(logs_contain("Dropping instrument BTCUSDT as"));
+        }
+    }
+
+    #[rstest]
+    #[case("BTC-14FEB25", "2025-01-31T00:00:00Z", None)]
+    #[case(
+        "BTC-14FEB25-EXPIRED",
+        "2025-01-31T00:00:00Z",
+        Some("2025-02-14T08:00:00Z")
+    )]
+    #[tokio::test]
+    async fn test_instrument_availability(
+        #[case] symbol: &str,
+        #[case] expected_since: &str,
+        #[case] expected_to: Option<&str>,
+    ) {
+        let router = Router::new().route(
+            "/instruments/{exchange}/{symbol}",
+            get(
+                |axum::extract::Path((_, symbol)): axum::extract::Path<(String, String)>| async move {
+                    if symbol.ends_with("-EXPIRED") {
+                        load_test_json("instrument_future_expired.json")
+                    } else {
+                        load_test_json("instrument_future.json")
+                    }
+                },
+            ),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let (available_since, available_to) = client
+            .instrument_availability(Exchange::Deribit, symbol, None)
+            .await
+            .unwrap();
+
+        let to_nanos = |value: &str| {
+            UnixNanos::from(
+                value
+                    .parse::<chrono::DateTime<chrono::Utc>>()
+                    .unwrap()
+                    .timestamp_nanos_opt()
+                    .unwrap() as u64,
+            )
+        };
+        assert_eq!(available_since, to_nanos(expected_since));
+        assert_eq!(available_to, expected_to.map(to_nanos));
+    }
+
+    /// Returns a router serving a valid spot and a malformed perpetual (missing `inverse`).
+    fn malformed_router() -> Router {
+        let mut malformed: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        malformed.as_object_mut().unwrap().remove("inverse");
+        let malformed = malformed.to_string();
+
+        let body = format!("[{},{malformed}]", load_test_json("instrument_spot.json"));
+        Router::new()
+            .route(
+                "/instruments/{exchange}",
+                get(move || {
+                    let body = body.clone();
+                    async move { body }
+                }),
+            )
+            .route(
+                "/instruments/{exchange}/{symbol}",
+                get(move || {
+                    let malformed = malformed.clone();
+                    async move { malformed }
+                }),
+            )
+    }
+
+    #[rstest]
+    #[tokio::test]
+    #[traced_test]
+    async fn test_instruments_reports_skipped_instrument() {
+        let base_url = start_mock_server(malformed_router()).await;
+        let client = test_client(&base_url);
+
+        let (instruments, skipped) = client
+            .instruments_with_skipped(
+                Exchange::Deribit,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // The spot definition yields one instrument per change window
+        assert_eq!(instruments.len(), 2);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].info.id.as_str(), "XBTUSD");
+        assert!(skipped[0].error.to_string().contains("`inverse`"));
+        assert!(logs_contain("Skipping instrument XBTUSD"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instrument_parse_error() {
+        let base_url = start_mock_server(malformed_router()).await;
+        let client = test_client(&base_url);
+
+        let result = TardisHttpClient::instrument(
+            &client,
+            Exchange::Bitmex,
+            "XBTUSD",
+            None,
+            None,
+            None,
+            &InstrumentOptions::default(),
+        )
+        .await;
+
+        match result {
+            Err(Error::InstrumentParse { symbol, message }) => {
+                assert_eq!(symbol, "XBTUSD");
+                assert!(message.contains("`inverse`"));
+            }
+            other => panic!("Expected `InstrumentParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case(InstrumentClass::Spot, true)]
+    #[case(InstrumentClass::Future, false)]
+    #[tokio::test]
+    async fn test_instruments_required(#[case] class: InstrumentClass, #[case] matches: bool) {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter)).await;
+        let client = test_client(&base_url);
+
+        let result = client
+            .instruments_required(
+                Exchange::Deribit,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions {
+                    classes: Some(vec![class]),
+                    ..Default::default()
+                },
+            )
+            .await;
+
+        if matches {
+            let instruments = result.unwrap();
+            assert!(!instruments.is_empty());
+            assert!(instruments
+                .iter()
+                .all(|instrument| instrument.instrument_class() == class));
+        } else {
+            match result {
+                Err(Error::NoInstrumentsMatched { exchange }) => {
+                    assert_eq!(exchange, Exchange::Deribit);
+                }
+                other => panic!("Expected `NoInstrumentsMatched`, was {other:?}"),
+            }
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_with_info() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                let mut invalid: serde_json::Value =
+                    serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+                invalid["id"] = "INVALID".into();
+                invalid["inverse"] = serde_json::Value::Null;
+                let body = format!(
+                    "[{},{},{invalid}]",
+                    load_test_json("instrument_spot.json"),
+                    load_test_json("instrument_perpetual_changes.json"),
+                );
+                async move { body }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let pairs = client
+            .instruments_with_info(Exchange::Bitmex, None, None, None, None, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(pairs.len(), 3);
+        for (info, instruments) in &pairs[..2] {
+            let expected = info.changes.as_ref().map_or(0, Vec::len) + 1;
+            assert_eq!(instruments.len(), expected);
+            assert!(instruments
+                .iter()
+                .all(|instrument| instrument.raw_symbol().as_str() == info.id.as_str()));
+        }
+        assert_eq!(pairs[2].0.id.as_str(), "INVALID");
+        assert!(pairs[2].1.is_empty());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_stream_matches_instruments() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { format!("[{}]", load_test_json("instrument_perpetual_changes.json")) }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let expected = client
+            .instruments(
+                Exchange::Bitmex,
+                None,
+                None,
+                None,
+                None,
+                &InstrumentOptions::default(),
+            )
+            .await
+            .unwrap();
+        let instruments: Vec<InstrumentAny> = client
+            .instruments_stream(Exchange::Bitmex, None, None, None, None, None, None)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert!(instruments.len() > 1);
+        assert_eq!(instruments.len(), expected.len());
+        assert_eq!(instruments, expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_stream_yields_error() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url =
+            start_mock_server(flaky_router(StatusCode::NOT_FOUND, 1, counter.clone())).await;
+        let client = test_client(&base_url);
+
+        let results: Vec<Result<InstrumentAny>> = client
+            .instruments_stream(Exchange::Deribit, None, None, None, None, None, None)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(Error::NotFound { .. })));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_filtered_stream() {
+        let resume = Arc::new(tokio::sync::Notify::new());
+        let resume_clone = resume.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                let resume = resume_clone.clone();
+                async move {
+                    let head = format!(
+                        "[{},{},",
+                        load_test_json("instrument_spot.json"),
+                        load_test_json("instrument_perpetual.json"),
+                    );
+                    // The rest of the body is only sent once the first match is consumed
+                    let tail = async move {
+                        resume.notified().await;
+                        Ok::<_, std::io::Error>(format!(
+                            "{}]",
+                            load_test_json("instrument_future.json")
+                        ))
+                    };
+                    let chunks = stream::once(future::ready(Ok(head))).chain(stream::once(tail));
+                    axum::body::Body::from_stream(chunks).into_response()
+                }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let instruments = client.instruments_filtered_stream(Exchange::Bitmex, |info| {
+            info.instrument_type != InstrumentType::Spot
+        });
+        tokio::pin!(instruments);
+
+        let first = tokio::time::timeout(Duration::from_secs(5), instruments.next())
+            .await
+            .expect("First match was not yielded before the full body was received")
+            .unwrap()
+            .unwrap();
+        resume.notify_one();
+        let rest: Vec<InstrumentAny> = instruments.map(Result::unwrap).collect().await;
+
+        assert_eq!(first.id(), InstrumentId::from("XBTUSD.BITMEX"));
+        assert_eq!(rest.len(), 1);
+        assert_eq!(rest[0].instrument_class(), InstrumentClass::Future);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_replay() {
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let requests_clone = requests.clone();
+        let router = Router::new().route(
+            "/data-feeds/{exchange}",
+            get(
+                move |axum::extract::Query(params): axum::extract::Query<
+                    HashMap<String, String>,
+                >| {
+                    let requests = requests_clone.clone();
+                    async move {
+                        let offset = params.get("offset").cloned().unwrap_or_default();
+                        requests.lock().unwrap().push(params);
+                        if offset == "0" {
+                            load_test_json("data_feed_bitmex.txt")
+                        } else {
+                            String::new()
+                        }
+                    }
+                },
+            ),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let request = ReplayRequest::new(
+            Exchange::Bitmex,
+            vec![ReplayFilter::new("trade", Some(vec!["XBTUSD".to_string()]))],
+            "2019-05-01T00:00:30Z".parse().unwrap(),
+            "2019-05-01T00:02:00Z".parse().unwrap(),
+        );
+
+        let messages: Vec<ReplayMessage> =
+            client.replay(request).map(Result::unwrap).collect().await;
+
+        // The message before the start of the range and the disconnect are skipped
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].message["data"][0]["side"], "Sell");
+        assert_eq!(
+            messages[1].local_timestamp,
+            "2019-05-01T00:00:59.9Z"
+                .parse::<chrono::DateTime<Utc>>()
+                .unwrap()
+        );
+
+        let requests = requests.lock().unwrap();
+        let offsets: Vec<&str> = requests
+            .iter()
+            .map(|params| params["offset"].as_str())
+            .collect();
+        assert_eq!(offsets, vec!["0", "1"]);
+        assert_eq!(requests[0]["from"], "2019-05-01");
+        assert_eq!(
+            requests[0]["filters"],
+            r#"[{"channel":"trade","symbols":["XBTUSD"]}]"#
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_replay_yields_request_error() {
+        let base_url = start_mock_server(Router::new()).await;
+        let client = test_client(&base_url);
+        let request = ReplayRequest::new(
+            Exchange::Bitmex,
+            vec![],
+            "2019-05-01T00:00:00Z".parse().unwrap(),
+            "2019-05-01T00:05:00Z".parse().unwrap(),
+        );
+
+        let results: Vec<Result<ReplayMessage>> = client.replay(request).collect().await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(Error::NotFound { .. })));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_batched() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async {
+                let infos: Vec<String> = [
+                    "instrument_spot.json",
+                    "instrument_perpetual.json",
+                    "instrument_future.json",
+                    "instrument_option.json",
+                    "instrument_spot_string_increments.json",
+                ]
+                .iter()
+                .map(|file| load_test_json(file))
+                .collect();
+                format!("[{}]", infos.join(","))
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let mut batch_sizes = Vec::new();
+        let count = client
+            .instruments_batched(Exchange::Deribit, 2, |batch| batch_sizes.push(batch.len()))
+            .await
+            .unwrap();
+
+        // Both spot definitions have a change, so each yields two instruments
+        assert_eq!(count, 7);
+        assert_eq!(batch_sizes, vec![2, 2, 2, 1]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_filtered_stream_yields_parse_error() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { format!("[{},{{", load_test_json("instrument_perpetual.json")) }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        let results: Vec<Result<InstrumentAny>> = client
+            .instruments_filtered_stream(Exchange::Bitmex, |_| true)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        match &results[1] {
+            Err(Error::ResponseParse { body_snippet, .. }) => {
+                assert!(body_snippet.starts_with("[{"));
+            }
+            other => panic!("Expected `ResponseParse`, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_info_stream_cancelled() {
+        let base_url = start_mock_server(slow_router(Duration::from_secs(5))).await;
+        let client = test_client(&base_url);
+        let cancellation = CancellationToken::new();
+        let cancellation_clone = cancellation.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            cancellation_clone.cancel();
+        });
+
+        let results: Vec<Result<InstrumentInfo>> = client
+            .instruments_info_stream(Exchange::Deribit, None, Some(cancellation), None)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(Error::Cancelled)));
+    }
+
+    #[rstest]
+    #[case(vec![InstrumentClass::Spot], vec!["BTC_USDC.DERIBIT"])]
+    #[case(vec![InstrumentClass::Swap], vec!["XBTUSD.BITMEX"])]
+    #[case(vec![InstrumentClass::Spot, InstrumentClass::Swap], vec!["BTC_USDC.DERIBIT", "XBTUSD.BITMEX"])]
+    #[case(vec![InstrumentClass::Future], vec![])]
+    #[tokio::test]
+    async fn test_instruments_filtered_by_class(
+        #[case] classes: Vec<InstrumentClass>,
+        #[case] expected: Vec<&str>,
+    ) {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async {
+                format!(
+                    "[{},{}]",
+                    load_test_json("instrument_spot.json"),
+                    load_test_json("instrument_perpetual.json"),
+                )
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+
+        // Only request the current definitions, excluding the spot change history
+        let start = Some(1_800_000_000_000_000_000);
+        let instruments = client
+            .instruments(
+                Exchange::Deribit,
+                start,
+                None,
+                None,
+                None,
+                &InstrumentOptions {
+                    classes: Some(classes),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = instruments.iter().map(|i| i.id().to_string()).collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_filtered_by_predicate() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async {
+                format!(
+                    "[{},{}]",
+                    load_test_json("instrument_spot.json"),
+                    load_test_json("instrument_perpetual.json"),
+                )
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let client = test_client(&base_url);
+        let max_tick_size = Price::from("0.5");
+        let below_tick_size =
+            move |instrument: &InstrumentAny| instrument.price_increment() <= max_tick_size;
+
+        let start = Some(1_800_000_000_000_000_000);
+        let instruments = client
+            .instruments(
+                Exchange::Deribit,
+                start,
+                None,
+                None,
+                None,
+                &InstrumentOptions {
+                    predicate: Some(Arc::new(below_tick_size)),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = instruments.iter().map(|i| i.id().to_string()).collect();
+        assert_eq!(ids, vec!["XBTUSD.BITMEX"]);
+    }
+
+    #[rstest]
+    fn test_builder_root_certificate() {
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .root_certificate_pem(load_test_json("test_ca.pem"))
+            .build()
+            .unwrap();
+
+        assert_eq!(client.tls_config.root_certificates.len(), 1);
+        assert!(!client.tls_config.accept_invalid_certs);
+    }
+
+    #[rstest]
+    fn test_builder_invalid_root_certificate() {
+        let result = TardisHttpClient::builder()
+            .api_key("test-key")
+            .root_certificate_pem("not a certificate")
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_builder_danger_accept_invalid_certs() {
+        let client = TardisHttpClient::builder()
+            .api_key("test-key")
+            .danger_accept_invalid_certs(true)
+            .build()
+            .unwrap();
+
+        assert!(client.tls_config.accept_invalid_certs);
+    }
+
+    #[rstest]
+    fn test_build_instruments_info_request() {
+        let client = test_client("https://api.tardis.dev/v1");
+        let filter = InstrumentFilter {
+            base_currency: Some(vec!["btc".to_string()]),
+            instrument_type: Some(vec!["perpetual".to_string(), "future".to_string()]),
+            ..Default::default()
+        };
+
+        let request = client
+            .build_instruments_info_request(&Exchange::Deribit, Some(&filter))
+            .unwrap();
+
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.tardis.dev/v1/instruments/deribit?filter=%7B%22baseCurrency%22%3A%5B%22BTC%22%5D%2C%22type%22%3A%5B%22perpetual%22%2C%22future%22%5D%7D"
+        );
+        let (_, filter_json) = request.url().query_pairs().next().unwrap();
+        assert_eq!(
+            filter_json,
+            r#"{"baseCurrency":["BTC"],"type":["perpetual","future"]}"#
+        );
+        let auth = request.headers().get("authorization").unwrap();
+        assert_eq!(auth, "Bearer test-key");
+        assert!(auth.is_sensitive());
+    }
+
+    #[rstest]
+    fn test_build_instruments_info_request_without_filter() {
+        let client = test_client("https://api.tardis.dev/v1");
+
+        let request = client
+            .build_instruments_info_request(&Exchange::Deribit, None)
+            .unwrap();
+
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.tardis.dev/v1/instruments/deribit"
+        );
+        assert_eq!(request.url().query(), None);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_instruments_info_with_meta() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let base_url = start_mock_server(flaky_router(StatusCode::OK, 0, counter.clone())).await;
+        let client = test_client(&base_url).with_cache(Duration::from_secs(60));
+        let filter = InstrumentFilter {
+            active: ActiveFilter::Active,
+            ..Default::default()
+        };
+
+        let (infos, meta) = client
+            .instruments_info_with_meta(Exchange::Deribit, Some(&filter), None)
+            .await
+            .unwrap();
+
+        assert_eq!(infos.len(), 1);
+        assert_eq!(
+            meta.url,
+            format!("{base_url}/instruments/deribit?filter=%7B%22active%22%3Atrue%7D")
+        );
+        assert_eq!(meta.status, 200);
+        assert!(meta.elapsed > Duration::ZERO);
+        assert_eq!(meta.size_bytes, instruments_json().len());
     }
 }
@@ -0,0 +1,297 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Comparison of instrument universe snapshots, such as to monitor new listings,
+//! delistings and specification changes between two fetches.
+
+use std::{collections::HashMap, fmt::Display};
+
+use nautilus_core::UnixNanos;
+use nautilus_model::{
+    identifiers::InstrumentId,
+    instruments::{Instrument, InstrumentAny},
+};
+
+use super::parse::as_instrument;
+
+/// A change to a single field of an instrument definition, with the values formatted
+/// for display (`None` for absent optional values).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// An instrument present in both snapshots, whose definition changed.
+#[derive(Debug, Clone)]
+pub struct ModifiedInstrument {
+    pub old: InstrumentAny,
+    pub new: InstrumentAny,
+    pub changes: Vec<FieldChange>,
+}
+
+/// The differences between two instrument snapshots, keyed by instrument ID.
+#[derive(Debug, Clone, Default)]
+pub struct InstrumentDiff {
+    /// Instruments only in the new snapshot (such as new listings).
+    pub added: Vec<InstrumentAny>,
+    /// Instruments only in the old snapshot (such as delistings).
+    pub removed: Vec<InstrumentAny>,
+    /// Instruments in both snapshots with a changed specification.
+    pub modified: Vec<ModifiedInstrument>,
+}
+
+impl InstrumentDiff {
+    /// Returns whether the snapshots have no differences.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+/// Compares the `old` and `new` instrument snapshots, returning the added, removed and
+/// modified instruments.
+///
+/// Where a snapshot holds several definitions for an instrument ID (such as the change
+/// history returned for a time range), the definition with the latest `ts_event` is
+/// compared. Timestamps are not compared, and results are ordered by instrument ID.
+#[must_use]
+pub fn diff_instruments(old: &[InstrumentAny], new: &[InstrumentAny]) -> InstrumentDiff {
+    let old = latest_by_id(old);
+    let new = latest_by_id(new);
+
+    let mut diff = InstrumentDiff::default();
+    for (instrument_id, new_instrument) in &new {
+        match old.get(instrument_id) {
+            Some(old_instrument) => {
+                let changes = field_changes(
+                    as_instrument(old_instrument),
+                    as_instrument(new_instrument),
+                );
+                if !changes.is_empty() {
+                    diff.modified.push(ModifiedInstrument {
+                        old: (*old_instrument).clone(),
+                        new: (*new_instrument).clone(),
+                        changes,
+                    });
+                }
+            }
+            None => diff.added.push((*new_instrument).clone()),
+        }
+    }
+    diff.removed = old
+        .iter()
+        .filter(|(instrument_id, _)| !new.contains_key(instrument_id))
+        .map(|(_, instrument)| (*instrument).clone())
+        .collect();
+
+    diff.added.sort_by_key(InstrumentAny::id);
+    diff.removed.sort_by_key(InstrumentAny::id);
+    diff.modified.sort_by_key(|modified| modified.new.id());
+    diff
+}
+
+/// Returns the definition with the latest `ts_event` for each instrument ID.
+fn latest_by_id(instruments: &[InstrumentAny]) -> HashMap<InstrumentId, &InstrumentAny> {
+    let mut latest: HashMap<InstrumentId, (UnixNanos, &InstrumentAny)> = HashMap::new();
+    for instrument in instruments {
+        let ts_event = as_instrument(instrument).ts_event();
+        latest
+            .entry(instrument.id())
+            .and_modify(|current| {
+                if ts_event >= current.0 {
+                    *current = (ts_event, instrument);
+                }
+            })
+            .or_insert((ts_event, instrument));
+    }
+    latest
+        .into_iter()
+        .map(|(instrument_id, (_, instrument))| (instrument_id, instrument))
+        .collect()
+}
+
+fn field_changes(old: &dyn Instrument, new: &dyn Instrument) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut compare = |field, old: Option<String>, new: Option<String>| {
+        if old != new {
+            changes.push(FieldChange {
+                field,
+                old: old.unwrap_or_else(|| "None".to_string()),
+                new: new.unwrap_or_else(|| "None".to_string()),
+            });
+        }
+    };
+
+    compare("raw_symbol", some(old.raw_symbol()), some(new.raw_symbol()));
+    compare(
+        "instrument_class",
+        some(old.instrument_class()),
+        some(new.instrument_class()),
+    );
+    compare(
+        "base_currency",
+        opt(old.base_currency()),
+        opt(new.base_currency()),
+    );
+    compare(
+        "quote_currency",
+        some(old.quote_currency()),
+        some(new.quote_currency()),
+    );
+    compare(
+        "settlement_currency",
+        some(old.settlement_currency()),
+        some(new.settlement_currency()),
+    );
+    compare("is_inverse", some(old.is_inverse()), some(new.is_inverse()));
+    compare(
+        "price_precision",
+        some(old.price_precision()),
+        some(new.price_precision()),
+    );
+    compare(
+        "size_precision",
+        some(old.size_precision()),
+        some(new.size_precision()),
+    );
+    compare(
+        "price_increment",
+        some(old.price_increment()),
+        some(new.price_increment()),
+    );
+    compare(
+        "size_increment",
+        some(old.size_increment()),
+        some(new.size_increment()),
+    );
+    compare("multiplier", some(old.multiplier()), some(new.multiplier()));
+    compare("lot_size", opt(old.lot_size()), opt(new.lot_size()));
+    compare(
+        "min_quantity",
+        opt(old.min_quantity()),
+        opt(new.min_quantity()),
+    );
+    compare(
+        "max_quantity",
+        opt(old.max_quantity()),
+        opt(new.max_quantity()),
+    );
+    compare(
+        "strike_price",
+        opt(old.strike_price()),
+        opt(new.strike_price()),
+    );
+    compare(
+        "activation_ns",
+        opt(old.activation_ns()),
+        opt(new.activation_ns()),
+    );
+    compare(
+        "expiration_ns",
+        opt(old.expiration_ns()),
+        opt(new.expiration_ns()),
+    );
+    compare(
+        "margin_init",
+        some(old.margin_init()),
+        some(new.margin_init()),
+    );
+    compare(
+        "margin_maint",
+        some(old.margin_maint()),
+        some(new.margin_maint()),
+    );
+    compare("maker_fee", some(old.maker_fee()), some(new.maker_fee()));
+    compare("taker_fee", some(old.taker_fee()), some(new.taker_fee()));
+
+    changes
+}
+
+fn some<T: Display>(value: T) -> Option<String> {
+    Some(value.to_string())
+}
+
+fn opt<T: Display>(value: Option<T>) -> Option<String> {
+    value.map(|value| value.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        http::{models::InstrumentInfo, parse::parse_instrument_any},
+        tests::load_test_json,
+    };
+
+    fn parse(file: &str, price_increment: Option<Decimal>) -> Vec<InstrumentAny> {
+        let mut info: InstrumentInfo = serde_json::from_str(&load_test_json(file)).unwrap();
+        if let Some(price_increment) = price_increment {
+            info.price_increment = price_increment;
+        }
+        parse_instrument_any(&info, None, None, None, false).unwrap()
+    }
+
+    #[rstest]
+    fn test_diff_identical_snapshots() {
+        let instruments = parse("instrument_spot.json", None);
+
+        let diff = diff_instruments(&instruments, &instruments);
+
+        assert!(diff.is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_added_removed_and_modified() {
+        let old = [
+            parse("instrument_spot.json", None),
+            parse("instrument_perpetual.json", None),
+        ]
+        .concat();
+        let new = [
+            parse("instrument_perpetual.json", Some(dec!(0.1))),
+            parse("instrument_future.json", None),
+        ]
+        .concat();
+
+        let diff = diff_instruments(&old, &new);
+
+        let added: Vec<InstrumentId> = diff.added.iter().map(InstrumentAny::id).collect();
+        let removed: Vec<InstrumentId> = diff.removed.iter().map(InstrumentAny::id).collect();
+        assert_eq!(added, vec![new.last().unwrap().id()]);
+        assert_eq!(removed, vec![InstrumentId::from("BTC_USDC.DERIBIT")]);
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(
+            diff.modified[0].new.id(),
+            InstrumentId::from("XBTUSD.BITMEX")
+        );
+        assert_eq!(
+            diff.modified[0].changes,
+            vec![FieldChange {
+                field: "price_increment",
+                old: "0.5".to_string(),
+                new: "0.1".to_string(),
+            }]
+        );
+    }
+}
@@ -0,0 +1,84 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::env;
+
+use super::{TARDIS_BASE_URL, TARDIS_SANDBOX_URL_ENV};
+
+/// The Tardis environment to send HTTP API requests to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TardisEnv {
+    /// The production API at [`TARDIS_BASE_URL`].
+    #[default]
+    Production,
+    /// The non-production API, at the URL set in the [`TARDIS_SANDBOX_URL_ENV`]
+    /// environment variable (the URL is not publicly documented).
+    Sandbox,
+}
+
+impl TardisEnv {
+    /// Returns the base URL for the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment is [`TardisEnv::Sandbox`] and the
+    /// [`TARDIS_SANDBOX_URL_ENV`] environment variable is not set.
+    pub fn base_url(&self) -> anyhow::Result<String> {
+        self.base_url_from(env::var(TARDIS_SANDBOX_URL_ENV).ok())
+    }
+
+    /// Returns the base URL for the environment, given the `sandbox_url` read from the
+    /// [`TARDIS_SANDBOX_URL_ENV`] environment variable.
+    fn base_url_from(&self, sandbox_url: Option<String>) -> anyhow::Result<String> {
+        match self {
+            Self::Production => Ok(TARDIS_BASE_URL.to_string()),
+            Self::Sandbox => sandbox_url.ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Sandbox base URL must be set in the '{TARDIS_SANDBOX_URL_ENV}' environment variable"
+                )
+            }),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_base_url() {
+        let sandbox_url = "https://sandbox.tardis.invalid/v1";
+
+        assert_eq!(
+            TardisEnv::Production
+                .base_url_from(Some(sandbox_url.to_string()))
+                .unwrap(),
+            TARDIS_BASE_URL
+        );
+        assert_eq!(
+            TardisEnv::Sandbox
+                .base_url_from(Some(sandbox_url.to_string()))
+                .unwrap(),
+            sandbox_url
+        );
+        assert!(TardisEnv::Sandbox.base_url_from(None).is_err());
+        assert_eq!(TardisEnv::default(), TardisEnv::Production);
+    }
+}
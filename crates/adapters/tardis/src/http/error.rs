@@ -13,23 +13,146 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use serde::Deserialize;
+use std::{marker::PhantomData, time::Duration};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use super::retry::is_retryable_status;
+use crate::enums::Exchange;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The maximum number of bytes of a response body included in an [`Error::ResponseParse`].
+pub const RESPONSE_SNIPPET_MAX_LEN: usize = 512;
+
+const SECRET_MASK: &str = "****";
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct TardisErrorResponse {
     pub code: u64,
     pub message: String,
 }
 
+/// Parses the `code` and `message` from an unsuccessful response `body`.
+///
+/// Tardis API errors are returned as a [`TardisErrorResponse`], however gateway or CDN
+/// failures may return another JSON shape, HTML or plain text. For JSON objects (or an
+/// object nested under `error`) the code is taken from a `code`, `statusCode` or `status`
+/// field, and the message from a `message`, `error`, `detail` or `msg` field, falling back
+/// to the HTTP `status` and the compact JSON. Otherwise the HTTP `status` is used as the
+/// code and the trimmed text as the message.
+pub(crate) fn parse_error_body(status: u16, body: &str) -> (u64, String) {
+    if let Ok(error) = serde_json::from_str::<TardisErrorResponse>(body) {
+        return (error.code, error.message);
+    }
+
+    let code = u64::from(status);
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(value) => {
+            let error = value
+                .get("error")
+                .filter(|error| error.is_object())
+                .unwrap_or(&value);
+            let code = ["code", "statusCode", "status"]
+                .iter()
+                .find_map(|key| error.get(*key).and_then(Value::as_u64))
+                .unwrap_or(code);
+            let message = ["message", "error", "detail", "msg"]
+                .iter()
+                .find_map(|key| error.get(*key).and_then(Value::as_str))
+                .map_or_else(|| value.to_string(), ToString::to_string);
+            (code, message)
+        }
+        Err(_) => (code, body.trim().to_string()),
+    }
+}
+
+/// Parses the code and message of unsuccessful responses in a custom error schema, such
+/// as that of a self-hosted Tardis-compatible gateway.
+///
+/// See [`TardisHttpClient::with_error_parser`](crate::http::TardisHttpClient::with_error_parser).
+pub trait ErrorBodyParser: Send + Sync {
+    /// Returns the code and message of the error in the response `body` with the HTTP
+    /// `status`, or `None` to fall back to the built-in parsing.
+    fn parse(&self, status: u16, body: &str) -> Option<(u64, String)>;
+}
+
+/// An [`ErrorBodyParser`] deserializing error bodies as JSON of the schema `T`, which is
+/// converted to a code and message.
+pub struct JsonErrorParser<T>(PhantomData<fn() -> T>);
+
+impl<T> JsonErrorParser<T> {
+    /// Creates a new [`JsonErrorParser`] instance.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Default for JsonErrorParser<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for JsonErrorParser<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple(stringify!(JsonErrorParser))
+            .field(&std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> ErrorBodyParser for JsonErrorParser<T>
+where
+    T: DeserializeOwned + Into<(u64, String)>,
+{
+    fn parse(&self, _status: u16, body: &str) -> Option<(u64, String)> {
+        serde_json::from_str::<T>(body).ok().map(Into::into)
+    }
+}
+
 /// HTTP errors for the Tardis HTTP client.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("HTTP request failed: {0}")]
     Request(#[from] reqwest::Error),
 
-    #[error("Tardis API error [{code}]: {message}")]
+    #[error("Tardis API unauthorized [{status}]: {message}")]
+    Unauthorized {
+        status: u16,
+        code: u64,
+        message: String,
+    },
+
+    #[error("Tardis API rate limit exceeded (retry after {retry_after:?}): {message}")]
+    RateLimited {
+        retry_after: Option<Duration>,
+        code: u64,
+        message: String,
+    },
+
+    #[error("Tardis API resource not found: {message}")]
+    NotFound { code: u64, message: String },
+
+    /// The Tardis API is temporarily unavailable (such as during maintenance), and
+    /// requested a delay before retrying with a `Retry-After` header.
+    #[error("Tardis API service unavailable (retry after {retry_after:?}): {message}")]
+    ServiceUnavailable {
+        retry_after: Duration,
+        code: u64,
+        message: String,
+    },
+
+    #[error("Tardis API server error [{status}]: {message}")]
+    ServerError {
+        status: u16,
+        code: u64,
+        message: String,
+    },
+
+    #[error("Tardis API error [{status}] (code {code}): {message}")]
     ApiError {
         status: u16,
         code: u64,
@@ -39,6 +162,468 @@ pub enum Error {
     #[error("Failed to parse response body as JSON: {0}")]
     JsonParse(#[from] serde_json::Error),
 
-    #[error("Failed to parse response as Tardis type: {0}")]
-    ResponseParse(String),
+    #[error("Failed to parse response as Tardis type [{status}]: {source}, body: {body_snippet}")]
+    ResponseParse {
+        status: u16,
+        #[source]
+        source: serde_json::Error,
+        /// The start of the response body, truncated to [`RESPONSE_SNIPPET_MAX_LEN`] bytes.
+        body_snippet: String,
+    },
+
+    /// The response body was empty (or only whitespace), which the Tardis API returns
+    /// transiently.
+    ///
+    /// The client does not retry this, as the body is only read once a request has
+    /// succeeded, so callers should retry the request themselves.
+    #[error("Tardis API returned an empty response body [{status}]")]
+    EmptyResponse { status: u16 },
+
+    /// The response body exceeded the maximum size, so reading it was aborted.
+    #[error("Response body exceeded the maximum of {limit} bytes")]
+    ResponseTooLarge { limit: usize },
+
+    /// No instruments matched a request which required at least one, such as with a
+    /// misconfigured filter.
+    #[error("No instruments matched for {exchange}")]
+    NoInstrumentsMatched { exchange: Exchange },
+
+    #[error("Failed to parse instrument {symbol}: {message}")]
+    InstrumentParse { symbol: String, message: String },
+
+    /// The request for one of several symbols failed.
+    #[error("Request for symbol {symbol} failed: {source}")]
+    Symbol {
+        symbol: String,
+        #[source]
+        source: Box<Error>,
+    },
+
+    #[error("Failed to write Parquet file: {0}")]
+    Parquet(String),
+
+    /// A redirect was not followed, such as a redirect loop, too many redirects, or a
+    /// cross-origin redirect which would forward the API key.
+    #[error("Redirect to {url} not followed: {message}")]
+    Redirect { url: String, message: String },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The circuit breaker is open after consecutive failures, so the request was not sent.
+    #[error("Circuit breaker open (retry in {retry_in:?})")]
+    CircuitOpen { retry_in: Duration },
+
+    /// The API key provider failed to load a key, so the request was not sent.
+    #[error("Failed to load Tardis API key: {0}")]
+    ApiKey(String),
+
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// The overall deadline for the request (including retries) passed before it completed.
+    #[error("Request deadline exceeded")]
+    DeadlineExceeded,
+
+    #[error("Request failed after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        attempts: u32,
+        #[source]
+        source: Box<Error>,
+    },
+}
+
+impl Error {
+    /// Returns whether the error is transient and the request may be retried.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Request(e) => e.is_timeout(),
+            Self::RateLimited { .. } | Self::ServiceUnavailable { .. } => true,
+            Self::ServerError { status, .. } | Self::ApiError { status, .. } => {
+                is_retryable_status(*status)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the delay requested by the server before retrying (if any).
+    #[must_use]
+    pub const fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited { retry_after, .. } => *retry_after,
+            Self::ServiceUnavailable { retry_after, .. } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Creates an [`Error::ResponseParse`] for a response `body` which failed to parse.
+    ///
+    /// The `error` is kept as the source, and any occurrences of the `secrets` (such as
+    /// API keys) are masked in the body snippet.
+    #[must_use]
+    pub fn response_parse(
+        status: u16,
+        error: serde_json::Error,
+        body: &str,
+        secrets: &[String],
+    ) -> Self {
+        Self::ResponseParse {
+            status,
+            source: error,
+            body_snippet: body_snippet(body, secrets, false),
+        }
+    }
+
+    /// Creates an [`Error::ResponseParse`] for a response which failed to parse, of which
+    /// only the start of the body `prefix` was kept, marking the snippet as truncated when
+    /// the body was `truncated`.
+    pub(crate) fn response_parse_prefix(
+        status: u16,
+        error: serde_json::Error,
+        prefix: &str,
+        truncated: bool,
+        secrets: &[String],
+    ) -> Self {
+        Self::ResponseParse {
+            status,
+            source: error,
+            body_snippet: body_snippet(prefix, secrets, truncated),
+        }
+    }
+
+    /// Maps an unsuccessful HTTP `status` and error `code` and `message` to the corresponding
+    /// variant, which keeps the code and message.
+    ///
+    /// A 503 with a `retry_after` delay is returned as [`Error::ServiceUnavailable`], and
+    /// statuses without a dedicated variant as [`Error::ApiError`].
+    #[must_use]
+    pub fn from_status(
+        status: u16,
+        code: u64,
+        message: String,
+        retry_after: Option<Duration>,
+    ) -> Self {
+        if let (503, Some(retry_after)) = (status, retry_after) {
+            return Self::ServiceUnavailable {
+                retry_after,
+                code,
+                message,
+            };
+        }
+
+        match status {
+            401 | 403 => Self::Unauthorized {
+                status,
+                code,
+                message,
+            },
+            404 => Self::NotFound { code, message },
+            429 => Self::RateLimited {
+                retry_after,
+                code,
+                message,
+            },
+            500..=599 => Self::ServerError {
+                status,
+                code,
+                message,
+            },
+            _ => Self::ApiError {
+                status,
+                code,
+                message,
+            },
+        }
+    }
+}
+
+/// Returns the start of `body` truncated to [`RESPONSE_SNIPPET_MAX_LEN`] bytes (on a char
+/// boundary), with any occurrences of the `secrets` masked.
+///
+/// The snippet is marked as truncated if the `body` was already `truncated`.
+fn body_snippet(body: &str, secrets: &[String], truncated: bool) -> String {
+    let masked = secrets
+        .iter()
+        .filter(|secret| !secret.is_empty())
+        .fold(body.to_string(), |masked, secret| {
+            masked.replace(secret.as_str(), SECRET_MASK)
+        });
+
+    if masked.len() <= RESPONSE_SNIPPET_MAX_LEN && !truncated {
+        return masked;
+    }
+
+    let mut end = RESPONSE_SNIPPET_MAX_LEN.min(masked.len());
+    while !masked.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!("{}...", &masked[..end])
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(401, "Unauthorized")]
+    #[case(403, "Unauthorized")]
+    #[case(404, "NotFound")]
+    #[case(429, "RateLimited")]
+    #[case(500, "ServerError")]
+    #[case(503, "ServerError")]
+    #[case(400, "ApiError")]
+    fn test_from_status(#[case] status: u16, #[case] expected: &str) {
+        let error = Error::from_status(status, 0, "error".to_string(), None);
+        let variant = match error {
+            Error::Unauthorized { .. } => "Unauthorized",
+            Error::NotFound { .. } => "NotFound",
+            Error::RateLimited { .. } => "RateLimited",
+            Error::ServerError { .. } => "ServerError",
+            Error::ApiError { .. } => "ApiError",
+            _ => "Other",
+        };
+        assert_eq!(variant, expected);
+    }
+
+    #[rstest]
+    #[case(401, false)]
+    #[case(404, false)]
+    #[case(429, true)]
+    #[case(501, false)]
+    #[case(502, true)]
+    fn test_is_retryable(#[case] status: u16, #[case] expected: bool) {
+        let error = Error::from_status(status, 0, "error".to_string(), None);
+        assert_eq!(error.is_retryable(), expected);
+    }
+
+    #[rstest]
+    #[case(401)]
+    #[case(404)]
+    #[case(429)]
+    #[case(500)]
+    #[case(503)]
+    fn test_from_status_keeps_code_and_message(#[case] status: u16) {
+        let error = Error::from_status(status, 42, "Gateway error".to_string(), None);
+
+        let (code, message) = match error {
+            Error::Unauthorized { code, message, .. }
+            | Error::NotFound { code, message }
+            | Error::RateLimited { code, message, .. }
+            | Error::ServerError { code, message, .. } => (code, message),
+            other => panic!("Unexpected error {other:?}"),
+        };
+        assert_eq!(code, 42);
+        assert_eq!(message, "Gateway error");
+    }
+
+    #[rstest]
+    fn test_retry_after() {
+        let retry_after = Some(Duration::from_secs(5));
+        let error = Error::from_status(429, 0, String::new(), retry_after);
+        assert_eq!(error.retry_after(), retry_after);
+    }
+
+    #[rstest]
+    fn test_service_unavailable_with_retry_after() {
+        let retry_after = Duration::from_secs(300);
+
+        let error = Error::from_status(503, 503, "Maintenance".to_string(), Some(retry_after));
+
+        assert!(matches!(error, Error::ServiceUnavailable { .. }));
+        assert!(error.is_retryable());
+        assert_eq!(error.retry_after(), Some(retry_after));
+    }
+
+    #[rstest]
+    fn test_service_unavailable_without_retry_after() {
+        let error = Error::from_status(503, 503, "Unavailable".to_string(), None);
+
+        assert!(matches!(error, Error::ServerError { status: 503, .. }));
+        assert_eq!(error.retry_after(), None);
+    }
+
+    #[rstest]
+    fn test_empty_response_not_retryable() {
+        let error = Error::EmptyResponse { status: 200 };
+
+        assert!(!error.is_retryable());
+    }
+
+    #[rstest]
+    fn test_display_includes_status_and_code() {
+        let error = Error::from_status(400, 100, "Invalid filter".to_string(), None);
+
+        assert_eq!(
+            error.to_string(),
+            "Tardis API error [400] (code 100): Invalid filter"
+        );
+    }
+
+    #[rstest]
+    fn test_request_error_source() {
+        let request_error = reqwest::Client::new().get("not a url").build().unwrap_err();
+        let error = Error::from(request_error);
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(source.downcast_ref::<reqwest::Error>().is_some());
+        assert!(error.to_string().starts_with("HTTP request failed: "));
+    }
+
+    #[rstest]
+    fn test_response_parse_source() {
+        let json_error = serde_json::from_str::<u64>("\"one\"").unwrap_err();
+        let json_message = json_error.to_string();
+
+        let error = Error::response_parse(200, json_error, "\"one\"", &[]);
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert_eq!(
+            source
+                .downcast_ref::<serde_json::Error>()
+                .unwrap()
+                .to_string(),
+            json_message
+        );
+        assert_eq!(
+            error.to_string(),
+            format!("Failed to parse response as Tardis type [200]: {json_message}, body: \"one\"")
+        );
+    }
+
+    #[rstest]
+    fn test_retries_exhausted_source() {
+        let error = Error::RetriesExhausted {
+            attempts: 3,
+            source: Box::new(Error::from_status(
+                502,
+                502,
+                "Bad Gateway".to_string(),
+                None,
+            )),
+        };
+
+        let source = std::error::Error::source(&error).unwrap();
+        assert!(matches!(
+            source.downcast_ref::<Box<Error>>().map(AsRef::as_ref),
+            Some(Error::ServerError { status: 502, .. })
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Request failed after 3 attempts: Tardis API server error [502]: Bad Gateway"
+        );
+    }
+
+    #[rstest]
+    fn test_body_snippet_truncates_long_body() {
+        let body = "é".repeat(RESPONSE_SNIPPET_MAX_LEN);
+
+        let snippet = body_snippet(&body, &["secret".to_string()], false);
+
+        assert!(snippet.ends_with("..."));
+        assert!(snippet.len() <= RESPONSE_SNIPPET_MAX_LEN + 3);
+    }
+
+    #[rstest]
+    fn test_body_snippet_marks_truncated_body() {
+        let snippet = body_snippet("[\"secret\",", &["secret".to_string()], true);
+
+        assert_eq!(snippet, "[\"****\",...");
+    }
+
+    #[rstest]
+    fn test_body_snippet_masks_secret() {
+        let snippet = body_snippet(
+            "{\"key\":\"secret-key\",\"other\":\"other-key\"}",
+            &["secret-key".to_string(), "other-key".to_string()],
+            false,
+        );
+
+        assert_eq!(snippet, "{\"key\":\"****\",\"other\":\"****\"}");
+    }
+
+    #[rstest]
+    fn test_parse_error_body_tardis_error() {
+        let (code, message) = parse_error_body(400, r#"{"code":100,"message":"Invalid filter"}"#);
+
+        assert_eq!(code, 100);
+        assert_eq!(message, "Invalid filter");
+    }
+
+    #[rstest]
+    #[case(r#"{"statusCode":7,"message":"Quota exceeded"}"#, 7, "Quota exceeded")]
+    #[case(
+        r#"{"error":{"code":8,"message":"Quota exceeded"}}"#,
+        8,
+        "Quota exceeded"
+    )]
+    #[case(r#"{"detail":"Quota exceeded"}"#, 400, "Quota exceeded")]
+    #[case(r#"{"error":"Quota exceeded"}"#, 400, "Quota exceeded")]
+    fn test_parse_error_body_known_shapes(
+        #[case] body: &str,
+        #[case] expected_code: u64,
+        #[case] expected_message: &str,
+    ) {
+        let (code, message) = parse_error_body(400, body);
+
+        assert_eq!(code, expected_code);
+        assert_eq!(message, expected_message);
+    }
+
+    #[derive(Deserialize)]
+    struct GatewayError {
+        id: u64,
+        reason: String,
+    }
+
+    impl From<GatewayError> for (u64, String) {
+        fn from(error: GatewayError) -> Self {
+            (error.id, error.reason)
+        }
+    }
+
+    #[rstest]
+    fn test_json_error_parser() {
+        let parser = JsonErrorParser::<GatewayError>::new();
+
+        assert_eq!(
+            parser.parse(400, r#"{"id":42,"reason":"Unknown dataset"}"#),
+            Some((42, "Unknown dataset".to_string()))
+        );
+        assert_eq!(parser.parse(400, r#"{"code":100,"message":"Other"}"#), None);
+    }
+
+    #[rstest]
+    fn test_parse_error_body_html() {
+        let body = "\n<html><body><h1>502 Bad Gateway</h1></body></html>\n";
+
+        let (code, message) = parse_error_body(502, body);
+
+        assert_eq!(code, 502);
+        assert_eq!(
+            message,
+            "<html><body><h1>502 Bad Gateway</h1></body></html>"
+        );
+    }
+
+    #[rstest]
+    fn test_parse_error_body_json_array() {
+        let (code, message) = parse_error_body(400, r#"[{"error":"bad request"}]"#);
+
+        assert_eq!(code, 400);
+        assert_eq!(message, r#"[{"error":"bad request"}]"#);
+    }
+
+    #[rstest]
+    fn test_parse_error_body_json_object() {
+        let (code, message) = parse_error_body(503, r#"{"error":"Service Unavailable"}"#);
+
+        assert_eq!(code, 503);
+        assert_eq!(message, "Service Unavailable");
+    }
 }
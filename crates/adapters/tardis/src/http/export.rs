@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conversion of Tardis instrument definitions to Arrow record batches, for snapshots of
+//! the instrument universe, and of Nautilus instruments to flat records for analysis.
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{Float64Array, RecordBatch, StringArray, UInt64Array},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+};
+use nautilus_model::instruments::InstrumentAny;
+use rust_decimal::prelude::ToPrimitive;
+use serde::Serialize;
+
+use super::{
+    models::InstrumentInfo,
+    parse::{parse_datetime_to_unix_nanos, parse_info_instrument_id},
+};
+use crate::parse::SymbolNormalizer;
+
+/// Returns the Arrow schema of an instruments snapshot, with one row per instrument.
+///
+/// Availability timestamps are UNIX nanoseconds, with a null `available_to` while data
+/// is still being collected for the instrument.
+#[must_use]
+pub fn instruments_schema() -> Schema {
+    Schema::new(vec![
+        Field::new("instrument_id", DataType::Utf8, false),
+        Field::new("symbol", DataType::Utf8, false),
+        Field::new("exchange", DataType::Utf8, false),
+        Field::new("instrument_type", DataType::Utf8, false),
+        Field::new("base_currency", DataType::Utf8, false),
+        Field::new("quote_currency", DataType::Utf8, false),
+        Field::new("price_increment", DataType::Float64, false),
+        Field::new("available_since", DataType::UInt64, false),
+        Field::new("available_to", DataType::UInt64, true),
+    ])
+}
+
+/// Converts the Tardis instrument `infos` to a record batch with the
+/// [`instruments_schema`], using the current specification of each instrument.
+///
+/// The `instrument_id` column holds the Nautilus instrument ID, with the symbol mapped by
+/// the `normalizer`, while `symbol` holds the raw Tardis symbol.
+///
+/// # Errors
+///
+/// Returns an error if the record batch fails to build.
+pub fn instruments_to_record_batch(
+    infos: &[InstrumentInfo],
+    normalizer: &dyn SymbolNormalizer,
+) -> Result<RecordBatch, ArrowError> {
+    let instrument_ids: StringArray = infos
+        .iter()
+        .map(|info| Some(parse_info_instrument_id(info, normalizer).to_string()))
+        .collect();
+    let symbols: StringArray = infos.iter().map(|info| Some(info.id.as_str())).collect();
+    let exchanges: StringArray = infos
+        .iter()
+        .map(|info| Some(info.exchange.to_string()))
+        .collect();
+    let instrument_types: StringArray = infos
+        .iter()
+        .map(|info| Some(info.instrument_type.to_string()))
+        .collect();
+    let base_currencies: StringArray = infos
+        .iter()
+        .map(|info| Some(info.base_currency.as_str()))
+        .collect();
+    let quote_currencies: StringArray = infos
+        .iter()
+        .map(|info| Some(info.quote_currency.as_str()))
+        .collect();
+    let price_increments: Float64Array = infos
+        .iter()
+        .map(|info| info.price_increment.to_f64())
+        .collect();
+    let available_since: UInt64Array = infos
+        .iter()
+        .map(|info| parse_datetime_to_unix_nanos(Some(info.available_since)).as_u64())
+        .collect();
+    let available_to: UInt64Array = infos
+        .iter()
+        .map(|info| {
+            info.available_to
+                .map(|available_to| parse_datetime_to_unix_nanos(Some(available_to)).as_u64())
+        })
+        .collect();
+
+    RecordBatch::try_new(
+        Arc::new(instruments_schema()),
+        vec![
+            Arc::new(instrument_ids),
+            Arc::new(symbols),
+            Arc::new(exchanges),
+            Arc::new(instrument_types),
+            Arc::new(base_currencies),
+            Arc::new(quote_currencies),
+            Arc::new(price_increments),
+            Arc::new(available_since),
+            Arc::new(available_to),
+        ],
+    )
+}
+
+/// A Nautilus instrument definition flattened to plain scalar fields, suitable for
+/// columnar conversion (such as to a DataFrame).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InstrumentRecord {
+    /// The Nautilus instrument ID.
+    pub instrument_id: String,
+    /// The Nautilus symbol.
+    pub symbol: String,
+    /// The raw exchange symbol.
+    pub raw_symbol: String,
+    /// The venue.
+    pub venue: String,
+    /// The instrument class, such as `SPOT` or `FUTURE`.
+    pub instrument_class: String,
+    /// The base currency, if any.
+    pub base_currency: Option<String>,
+    /// The quote currency.
+    pub quote_currency: String,
+    /// The minimum price increment.
+    pub tick_size: f64,
+    /// The contract multiplier.
+    pub multiplier: f64,
+    /// The expiration timestamp in UNIX nanoseconds, if any.
+    pub expiry: Option<u64>,
+}
+
+impl From<&InstrumentAny> for InstrumentRecord {
+    fn from(instrument: &InstrumentAny) -> Self {
+        let instrument_id = instrument.id();
+        Self {
+            instrument_id: instrument_id.to_string(),
+            symbol: instrument_id.symbol.to_string(),
+            raw_symbol: instrument.raw_symbol().to_string(),
+            venue: instrument_id.venue.to_string(),
+            instrument_class: instrument.instrument_class().to_string(),
+            base_currency: instrument
+                .base_currency()
+                .map(|currency| currency.code.to_string()),
+            quote_currency: instrument.quote_currency().code.to_string(),
+            tick_size: instrument.price_increment().as_f64(),
+            multiplier: instrument.multiplier().as_f64(),
+            expiry: instrument.expiration_ns().map(|expiry| expiry.as_u64()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::array::Array;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::Exchange,
+        http::parse::parse_instrument_any,
+        parse::{DefaultSymbolNormalizer, RawSymbolNormalizer},
+        tests::load_test_json,
+    };
+
+    #[rstest]
+    fn test_instruments_to_record_batch() {
+        let infos: Vec<InstrumentInfo> = ["instrument_spot.json", "instrument_future_expired.json"]
+            .iter()
+            .map(|file| serde_json::from_str(&load_test_json(file)).unwrap())
+            .collect();
+
+        let batch = instruments_to_record_batch(&infos, &DefaultSymbolNormalizer).unwrap();
+
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().as_ref(), &instruments_schema());
+
+        let symbols = batch
+            .column_by_name("symbol")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(symbols.value(0), "BTC_USDC");
+
+        let available_to = batch
+            .column_by_name("available_to")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<UInt64Array>()
+            .unwrap();
+        assert!(available_to.is_null(0));
+        assert!(!available_to.is_null(1));
+    }
+
+    #[rstest]
+    fn test_instruments_to_record_batch_ids_match_parsed(
+        #[values(true, false)] normalize_symbols: bool,
+    ) {
+        let mut info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        info.exchange = Exchange::Bybit;
+        info.id = "BTCUSDT".into();
+        info.inverse = Some(false);
+        let normalizer: &dyn SymbolNormalizer = if normalize_symbols {
+            &DefaultSymbolNormalizer
+        } else {
+            &RawSymbolNormalizer
+        };
+
+        let batch = instruments_to_record_batch(&[info.clone()], normalizer).unwrap();
+        let instrument = parse_instrument_any(&info, None, None, None, normalize_symbols)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let instrument_ids = batch
+            .column_by_name("instrument_id")
+            .unwrap()
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(instrument_ids.value(0), instrument.id().to_string());
+    }
+
+    #[rstest]
+    fn test_instrument_record_from_instrument() {
+        let info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_future.json")).unwrap();
+        let instrument = parse_instrument_any(&info, None, None, None, false)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let record = InstrumentRecord::from(&instrument);
+
+        assert_eq!(
+            record,
+            InstrumentRecord {
+                instrument_id: "BTC-14FEB25.DERIBIT".to_string(),
+                symbol: "BTC-14FEB25".to_string(),
+                raw_symbol: "BTC-14FEB25".to_string(),
+                venue: "DERIBIT".to_string(),
+                instrument_class: "FUTURE".to_string(),
+                base_currency: None,
+                quote_currency: "USD".to_string(),
+                tick_size: 2.5,
+                multiplier: 1.0,
+                expiry: Some(1_739_520_000_000_000_000),
+            }
+        );
+    }
+}
@@ -13,6 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::str::FromStr;
+
+use anyhow::Context;
 use nautilus_core::UnixNanos;
 use nautilus_model::{
     currencies::CURRENCY_MAP,
@@ -27,6 +30,12 @@ use ustr::Ustr;
 use super::models::InstrumentInfo;
 use crate::parse::parse_option_kind;
 
+/// Parses the minimum trade amount of the given `info`, with the precision of its
+/// decimal representation.
+fn parse_min_trade_amount(info: &InstrumentInfo) -> anyhow::Result<Quantity> {
+    Quantity::from_str(&info.min_trade_amount.normalize().to_string()).map_err(anyhow::Error::msg)
+}
+
 /// Returns the currency either from the internal currency map or creates a default crypto.
 fn get_currency(code: &str) -> Currency {
     CURRENCY_MAP
@@ -38,7 +47,6 @@ fn get_currency(code: &str) -> Currency {
 }
 
 #[allow(clippy::too_many_arguments)]
-#[must_use]
 pub fn create_currency_pair(
     info: &InstrumentInfo,
     instrument_id: InstrumentId,
@@ -51,8 +59,8 @@ pub fn create_currency_pair(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
-    InstrumentAny::CurrencyPair(CurrencyPair::new(
+) -> anyhow::Result<InstrumentAny> {
+    Ok(InstrumentAny::CurrencyPair(CurrencyPair::new_checked(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
@@ -63,7 +71,7 @@ pub fn create_currency_pair(
         size_increment,
         None, // lot_size TBD
         None,
-        Some(Quantity::from(info.min_trade_amount.to_string().as_str())),
+        Some(parse_min_trade_amount(info)?),
         None,
         None,
         None,
@@ -74,11 +82,10 @@ pub fn create_currency_pair(
         Some(taker_fee),
         ts_event,
         ts_init,
-    ))
+    )?))
 }
 
 #[allow(clippy::too_many_arguments)]
-#[must_use]
 pub fn create_crypto_perpetual(
     info: &InstrumentInfo,
     instrument_id: InstrumentId,
@@ -92,42 +99,44 @@ pub fn create_crypto_perpetual(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
-    InstrumentAny::CryptoPerpetual(CryptoPerpetual::new(
-        instrument_id,
-        raw_symbol,
-        get_currency(info.base_currency.to_uppercase().as_str()),
-        get_currency(info.quote_currency.to_uppercase().as_str()),
-        get_currency(
-            info.settlement_currency
-                .unwrap_or(info.quote_currency)
-                .to_uppercase()
-                .as_str(),
-        ),
-        info.inverse.expect("Perpetual should have `inverse` field"),
-        price_increment.precision,
-        size_increment.precision,
-        price_increment,
-        size_increment,
-        multiplier,
-        None, // lot_size TBD
-        None,
-        Some(Quantity::from(info.min_trade_amount.to_string().as_str())),
-        None,
-        None,
-        None,
-        None,
-        Some(margin_init),
-        Some(margin_maint),
-        Some(maker_fee),
-        Some(taker_fee),
-        ts_event,
-        ts_init,
+) -> anyhow::Result<InstrumentAny> {
+    Ok(InstrumentAny::CryptoPerpetual(
+        CryptoPerpetual::new_checked(
+            instrument_id,
+            raw_symbol,
+            get_currency(info.base_currency.to_uppercase().as_str()),
+            get_currency(info.quote_currency.to_uppercase().as_str()),
+            get_currency(
+                info.settlement_currency
+                    .unwrap_or(info.quote_currency)
+                    .to_uppercase()
+                    .as_str(),
+            ),
+            info.inverse
+                .context("Perpetual should have `inverse` field")?,
+            price_increment.precision,
+            size_increment.precision,
+            price_increment,
+            size_increment,
+            multiplier,
+            None, // lot_size TBD
+            None,
+            Some(parse_min_trade_amount(info)?),
+            None,
+            None,
+            None,
+            None,
+            Some(margin_init),
+            Some(margin_maint),
+            Some(maker_fee),
+            Some(taker_fee),
+            ts_event,
+            ts_init,
+        )?,
     ))
 }
 
 #[allow(clippy::too_many_arguments)]
-#[must_use]
 pub fn create_crypto_future(
     info: &InstrumentInfo,
     instrument_id: InstrumentId,
@@ -143,14 +152,14 @@ pub fn create_crypto_future(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
-    InstrumentAny::CryptoFuture(CryptoFuture::new(
+) -> anyhow::Result<InstrumentAny> {
+    Ok(InstrumentAny::CryptoFuture(CryptoFuture::new_checked(
         instrument_id,
         raw_symbol,
         get_currency(info.base_currency.to_uppercase().as_str()),
         get_currency(info.quote_currency.to_uppercase().as_str()),
         get_currency(info.base_currency.to_uppercase().as_str()),
-        info.inverse.expect("Future should have `inverse` field"),
+        info.inverse.context("Future should have `inverse` field")?,
         activation,
         expiration,
         price_increment.precision,
@@ -160,7 +169,7 @@ pub fn create_crypto_future(
         multiplier,
         None, // lot_size TBD
         None,
-        Some(Quantity::from(info.min_trade_amount.to_string().as_str())),
+        Some(parse_min_trade_amount(info)?),
         None,
         None,
         None,
@@ -171,11 +180,10 @@ pub fn create_crypto_future(
         Some(taker_fee),
         ts_event,
         ts_init,
-    ))
+    )?))
 }
 
 #[allow(clippy::too_many_arguments)]
-#[must_use]
 pub fn create_option_contract(
     info: &InstrumentInfo,
     instrument_id: InstrumentId,
@@ -190,8 +198,8 @@ pub fn create_option_contract(
     taker_fee: Decimal,
     ts_event: UnixNanos,
     ts_init: UnixNanos,
-) -> InstrumentAny {
-    InstrumentAny::OptionContract(OptionContract::new(
+) -> anyhow::Result<InstrumentAny> {
+    Ok(InstrumentAny::OptionContract(OptionContract::new_checked(
         instrument_id,
         raw_symbol,
         AssetClass::Cryptocurrency,
@@ -200,13 +208,15 @@ pub fn create_option_contract(
         parse_option_kind(
             info.option_type
                 .clone()
-                .expect("Option should have `option_type` field"),
+                .context("Option should have `option_type` field")?,
         ),
-        Price::new(
+        Price::from_str(&format!(
+            "{:.*}",
+            usize::from(price_increment.precision),
             info.strike_price
-                .expect("Option should have `strike_price` field"),
-            price_increment.precision,
-        ),
+                .context("Option should have `strike_price` field")?,
+        ))
+        .map_err(anyhow::Error::msg)?,
         get_currency(info.quote_currency.to_uppercase().as_str()),
         activation,
         expiration,
@@ -215,7 +225,7 @@ pub fn create_option_contract(
         multiplier.unwrap_or(Quantity::from(1)),
         Quantity::from(1), // lot_size
         None,
-        Some(Quantity::from(info.min_trade_amount.to_string().as_str())),
+        Some(parse_min_trade_amount(info)?),
         None,
         None,
         Some(margin_init),
@@ -224,5 +234,5 @@ pub fn create_option_contract(
         Some(taker_fee),
         ts_event,
         ts_init,
-    ))
+    )?))
 }
@@ -0,0 +1,270 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! API key rotation for the Tardis HTTP client.
+//!
+//! Requests are sent with the active key of the pool, which advances to the next key
+//! (wrapping around) whenever a key is rate limited or rejected. Alternatively the key is
+//! loaded by an [`ApiKeyProvider`] for every request, so rotated secrets are picked up
+//! without rebuilding the client.
+
+use std::{
+    fmt::Debug,
+    fs,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+/// How the API key is attached to requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AuthStyle {
+    /// An `Authorization: Bearer <key>` header, as expected by the Tardis API.
+    #[default]
+    Bearer,
+    /// A header with the given name and the key as its value (such as `x-api-key`), as
+    /// expected by some self-hosted gateways.
+    Header(String),
+}
+
+/// A callback returning the current Tardis API key, such as from a file or secret manager.
+pub type ApiKeyProvider = Arc<dyn Fn() -> anyhow::Result<String> + Send + Sync>;
+
+/// A pool of Tardis API keys, shared between clones of a client.
+pub struct ApiKeyPool {
+    keys: Vec<String>,
+    active: AtomicUsize,
+    provider: Option<ApiKeyProvider>,
+}
+
+impl Debug for ApiKeyPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ApiKeyPool))
+            .field("keys", &self.keys.len())
+            .field("active", &self.active)
+            .field("provider", &self.provider.is_some())
+            .finish()
+    }
+}
+
+impl ApiKeyPool {
+    /// Creates a new [`ApiKeyPool`] instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `keys` is empty or contains an empty key.
+    pub fn new(keys: Vec<String>) -> anyhow::Result<Self> {
+        if keys.is_empty() {
+            anyhow::bail!("At least one API key must be provided");
+        }
+        if keys.iter().any(String::is_empty) {
+            anyhow::bail!("API keys must not be empty");
+        }
+
+        Ok(Self {
+            keys,
+            active: AtomicUsize::new(0),
+            provider: None,
+        })
+    }
+
+    /// Creates a new [`ApiKeyPool`] instance which loads the key from the `provider` for
+    /// every request.
+    #[must_use]
+    pub fn from_provider(provider: ApiKeyProvider) -> Self {
+        Self {
+            keys: Vec::new(),
+            active: AtomicUsize::new(0),
+            provider: Some(provider),
+        }
+    }
+
+    /// Returns the number of keys in the pool (zero when keys are loaded by a provider).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns whether the pool is empty (only true when keys are loaded by a provider).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns whether keys are loaded by a provider.
+    #[must_use]
+    pub fn has_provider(&self) -> bool {
+        self.provider.is_some()
+    }
+
+    /// Returns the index of the active key.
+    #[must_use]
+    pub fn active_index(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    /// Returns the index and value of the active key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if keys are loaded by a provider, use [`ApiKeyPool::current`] instead.
+    #[must_use]
+    pub fn active(&self) -> (usize, &str) {
+        let index = self.active_index();
+        (index, &self.keys[index])
+    }
+
+    /// Returns the index and value of the key to authenticate the next request with,
+    /// calling the provider (if set) to load it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider fails or returns an empty key.
+    pub fn current(&self) -> anyhow::Result<(usize, String)> {
+        match &self.provider {
+            Some(provider) => {
+                let key = provider()?;
+                if key.is_empty() {
+                    anyhow::bail!("API key provider returned an empty key");
+                }
+                Ok((0, key))
+            }
+            None => {
+                let (index, key) = self.active();
+                Ok((index, key.to_string()))
+            }
+        }
+    }
+
+    /// Returns all keys in the pool (empty when keys are loaded by a provider).
+    #[must_use]
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+
+    /// Advances to the next key if the key at `index` is still active.
+    ///
+    /// Concurrent failures of the same key therefore only advance the pool once.
+    pub fn advance_from(&self, index: usize) {
+        if self.keys.is_empty() {
+            return;
+        }
+        let next = (index + 1) % self.keys.len();
+        if next != index
+            && self
+                .active
+                .compare_exchange(index, next, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+        {
+            tracing::warn!("Rotating from API key index {index} to {next}");
+        }
+    }
+}
+
+/// Reads a Tardis API key from the file at `path`, ignoring surrounding whitespace.
+///
+/// # Errors
+///
+/// Returns an error if the file fails to read or contains no key.
+pub fn read_api_key_file(path: &Path) -> anyhow::Result<String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read API key file {}: {e}", path.display()))?;
+    let key = contents.trim();
+    if key.is_empty() {
+        anyhow::bail!("API key file {} is empty", path.display());
+    }
+    Ok(key.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn keys(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("key-{i}")).collect()
+    }
+
+    #[rstest]
+    fn test_new_rejects_empty_pool() {
+        assert!(ApiKeyPool::new(Vec::new()).is_err());
+        assert!(ApiKeyPool::new(vec![String::new()]).is_err());
+    }
+
+    #[rstest]
+    fn test_advance_wraps_around() {
+        let pool = ApiKeyPool::new(keys(2)).unwrap();
+        assert_eq!(pool.active(), (0, "key-0"));
+
+        pool.advance_from(0);
+        assert_eq!(pool.active(), (1, "key-1"));
+
+        pool.advance_from(1);
+        assert_eq!(pool.active(), (0, "key-0"));
+    }
+
+    #[rstest]
+    fn test_advance_from_stale_index_is_ignored() {
+        let pool = ApiKeyPool::new(keys(3)).unwrap();
+
+        pool.advance_from(0);
+        pool.advance_from(0);
+
+        assert_eq!(pool.active_index(), 1);
+    }
+
+    #[rstest]
+    fn test_provider_is_called_for_each_key() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let pool = ApiKeyPool::from_provider(Arc::new(move || {
+            let n = calls_clone.fetch_add(1, Ordering::SeqCst);
+            Ok(format!("rotated-{n}"))
+        }));
+
+        assert_eq!(pool.current().unwrap(), (0, "rotated-0".to_string()));
+        assert_eq!(pool.current().unwrap(), (0, "rotated-1".to_string()));
+
+        // Advancing a provider pool is a no-op
+        pool.advance_from(0);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[rstest]
+    fn test_provider_rejects_empty_key() {
+        let pool = ApiKeyPool::from_provider(Arc::new(|| Ok(String::new())));
+        assert!(pool.current().is_err());
+    }
+
+    #[rstest]
+    fn test_read_api_key_file() {
+        let path = std::env::temp_dir().join(format!("tardis-key-{}.txt", uuid::Uuid::new_v4()));
+
+        fs::write(&path, "file-key\n").unwrap();
+        assert_eq!(read_api_key_file(&path).unwrap(), "file-key");
+
+        fs::write(&path, " \n").unwrap();
+        assert!(read_api_key_file(&path).is_err());
+
+        fs::remove_file(&path).unwrap();
+        assert!(read_api_key_file(&path).is_err());
+    }
+}
@@ -0,0 +1,39 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Metrics hooks for the Tardis HTTP client.
+//!
+//! Implement [`TardisMetrics`] to record request counts, latencies and error rates in an
+//! external metrics system (e.g. Prometheus) without modifying the client.
+
+use std::time::Duration;
+
+/// Callbacks invoked by a [`TardisHttpClient`](super::TardisHttpClient) around each HTTP request.
+///
+/// The `endpoint` is the request path relative to the base URL (without the query), such as
+/// `/instruments/deribit`. Each retry attempt is reported as a separate request.
+/// All methods default to no-ops, so implementors only need to override those of interest.
+pub trait TardisMetrics: Send + Sync {
+    /// Called before a request is sent.
+    fn on_request(&self, _endpoint: &str) {}
+
+    /// Called when a response is received (successful or not) with its HTTP `status`
+    /// and the `elapsed` time since the request was sent.
+    fn on_response(&self, _endpoint: &str, _status: u16, _elapsed: Duration) {}
+
+    /// Called when a request fails, either without a response (e.g. a timeout)
+    /// or with an unsuccessful HTTP status.
+    fn on_error(&self, _endpoint: &str) {}
+}
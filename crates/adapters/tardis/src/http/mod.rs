@@ -13,13 +13,33 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod breaker;
+pub mod cache;
 pub mod client;
+pub mod diff;
+pub mod env;
 pub mod error;
+pub mod export;
 pub mod instruments;
+pub mod keys;
+pub mod metrics;
 pub mod models;
 pub mod parse;
 pub mod query;
+pub mod ratelimit;
+pub mod replay;
+pub mod retry;
+#[cfg(feature = "vcr")]
+pub mod vcr;
 
-pub use crate::http::client::TardisHttpClient;
+pub use crate::http::{
+    client::{TardisHttpClient, TardisHttpClientBuilder},
+    env::TardisEnv,
+};
 
 pub const TARDIS_BASE_URL: &str = "https://api.tardis.dev/v1";
+
+/// The environment variable holding the base URL for [`TardisEnv::Sandbox`].
+pub const TARDIS_SANDBOX_URL_ENV: &str = "TARDIS_SANDBOX_URL";
@@ -13,13 +13,23 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use std::{collections::BTreeMap, fmt, time::Duration};
+
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
+use rust_decimal::Decimal;
+use serde::{
+    de::{IgnoredAny, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::Value;
 use ustr::Ustr;
 
 use crate::{
-    enums::{Exchange, InstrumentType, OptionType},
-    parse::deserialize_uppercase,
+    enums::{BybitProductCategory, Exchange, InstrumentType, OptionType},
+    parse::{
+        deserialize_decimal, deserialize_option_datetime, deserialize_option_decimal,
+        deserialize_option_seconds, deserialize_uppercase,
+    },
 };
 
 #[derive(Debug, Clone, Deserialize)]
@@ -30,16 +40,16 @@ pub struct InstrumentChanges {
     pub until: DateTime<Utc>,
     /// The minimum price increment (tick size).
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
-    pub price_increment: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub price_increment: Option<Decimal>,
     /// The minimum size increment.
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
-    pub amount_increment: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub amount_increment: Option<Decimal>,
     /// The instrument contract multiplier (only for derivatives).
     #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(default)]
-    pub contract_multiplier: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub contract_multiplier: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -65,30 +75,216 @@ pub struct InstrumentInfo {
     pub available_since: DateTime<Utc>,
     /// The available to date in ISO format.
     pub available_to: Option<DateTime<Utc>>,
-    /// The contract expiry date in ISO format (applicable to futures and options).
+    /// The contract expiry date in ISO format, or occasionally as UNIX epoch milliseconds
+    /// (applicable to futures and options).
+    #[serde(default, deserialize_with = "deserialize_option_datetime")]
     pub expiry: Option<DateTime<Utc>>,
     /// The instrument price increment.
-    pub price_increment: f64,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub price_increment: Decimal,
     /// The instrument size increment.
-    pub amount_increment: f64,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub amount_increment: Decimal,
     /// The minimum tradeable size for the instrument.
-    pub min_trade_amount: f64,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub min_trade_amount: Decimal,
     /// The instrument maker fee: consider it as illustrative only, as it depends in practice on account traded volume levels, different categories, VIP levels, owning exchange currency etc.
-    pub maker_fee: f64,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub maker_fee: Decimal,
     /// The instrument taker fee: consider it as illustrative only, as it depends in practice on account traded volume levels, different categories, VIP levels, owning exchange currency etc.
-    pub taker_fee: f64,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub taker_fee: Decimal,
     /// If the instrument is inverse (only for derivatives such as futures and perpetual swaps).
     pub inverse: Option<bool>,
     /// The instrument contract multiplier (only for derivatives).
-    pub contract_multiplier: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub contract_multiplier: Option<Decimal>,
     /// If the instrument is quanto (only for quanto instruments).
     pub quanto: Option<bool>,
     /// The instrument settlement currency (only for Quanto instruments where settlement currency is different both base and quote currency).
     pub settlement_currency: Option<Ustr>,
     /// The instrument strike price (only for options).
-    pub strike_price: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_option_decimal")]
+    pub strike_price: Option<Decimal>,
     /// The option type (only for options).
     pub option_type: Option<OptionType>,
+    /// The interval between funding payments (only for perpetuals with a fixed interval).
+    /// Not parsed, as a Nautilus `CryptoPerpetual` has no funding interval field, so use
+    /// [`TardisHttpClient::instruments_with_info`](crate::http::TardisHttpClient::instruments_with_info)
+    /// to get it alongside the parsed instruments.
+    #[serde(default, deserialize_with = "deserialize_option_seconds")]
+    pub funding_rate_interval: Option<Duration>,
+    /// The instrument trading hours ([`TradingHours::Continuous`] for 24/7 markets).
+    #[serde(default)]
+    pub trading_hours: TradingHours,
     /// The changes for the instrument (best-effort basis from Tardis).
     pub changes: Option<Vec<InstrumentChanges>>,
+    /// Any other metadata fields by name (such as exchange-specific attributes).
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+impl InstrumentInfo {
+    /// Returns when the metadata of the instrument last changed: the latest of its listing
+    /// (`available_since`), specification changes and delisting (`available_to`).
+    #[must_use]
+    pub fn last_changed(&self) -> DateTime<Utc> {
+        self.changes
+            .iter()
+            .flatten()
+            .map(|change| change.until)
+            .chain(self.available_to)
+            .fold(self.available_since, DateTime::max)
+    }
+
+    /// Returns the Bybit product category of the instrument, or `None` if it is not listed
+    /// on Bybit (or is a combo).
+    #[must_use]
+    pub const fn bybit_category(&self) -> Option<BybitProductCategory> {
+        match self.exchange {
+            Exchange::Bybit | Exchange::BybitSpot | Exchange::BybitOptions => {
+                BybitProductCategory::from_instrument_type(&self.instrument_type, self.inverse)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A recurring trading session of a scheduled market, with times in UTC.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct TradingSession {
+    /// The days of the week the session opens on (every day when empty).
+    #[serde(default)]
+    pub days: Vec<Weekday>,
+    /// The time the session opens.
+    pub open: NaiveTime,
+    /// The time the session closes, on the following day if not after `open`.
+    pub close: NaiveTime,
+}
+
+impl TradingSession {
+    /// Returns whether the session is open at `time`.
+    #[must_use]
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        let opens_on = |day: Weekday| self.days.is_empty() || self.days.contains(&day);
+        let (day, time) = (time.weekday(), time.time());
+
+        if self.open < self.close {
+            opens_on(day) && time >= self.open && time < self.close
+        } else {
+            // Spans midnight, so may have opened the previous day
+            (opens_on(day) && time >= self.open) || (opens_on(day.pred()) && time < self.close)
+        }
+    }
+}
+
+/// The trading hours of an instrument.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TradingHours {
+    /// Trading never closes (24/7), as for most crypto markets.
+    #[default]
+    Continuous,
+    /// Trading is only open during the sessions.
+    Sessions(Vec<TradingSession>),
+}
+
+impl TradingHours {
+    /// Returns whether trading is open at `time`.
+    #[must_use]
+    pub fn is_open(&self, time: DateTime<Utc>) -> bool {
+        match self {
+            Self::Continuous => true,
+            Self::Sessions(sessions) => sessions.iter().any(|session| session.is_open(time)),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TradingHours {
+    /// Deserializes from a list of sessions, where `null` or an empty list is continuous.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let sessions = Option::<Vec<TradingSession>>::deserialize(deserializer)?;
+        Ok(match sessions {
+            Some(sessions) if !sessions.is_empty() => Self::Sessions(sessions),
+            _ => Self::Continuous,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The details of an exchange supported by Tardis.
+/// See <https://docs.tardis.dev/api/http#exchanges>.
+pub struct ExchangeDetails {
+    /// The exchange ID.
+    pub id: Exchange,
+    /// The exchange display name.
+    pub name: String,
+    /// If data collection is currently enabled for the exchange.
+    pub enabled: bool,
+    /// If the exchange has been delisted (data remains available for the historical range).
+    #[serde(default)]
+    pub delisted: Option<bool>,
+    /// If downloadable CSV datasets are available for the exchange.
+    #[serde(default)]
+    pub supports_datasets: bool,
+    /// The available from date in ISO format.
+    pub available_since: DateTime<Utc>,
+    /// The available to date in ISO format (only for exchanges no longer collected).
+    #[serde(default)]
+    pub available_to: Option<DateTime<Utc>>,
+    /// The exchange-native channels available for replay.
+    #[serde(default)]
+    pub available_channels: Vec<String>,
+    /// The normalized data types available for the exchange.
+    #[serde(default)]
+    pub data_types: Vec<String>,
+}
+
+impl ExchangeDetails {
+    /// Returns whether `data_type` is available for the exchange, either as a normalized
+    /// data type (such as `incremental_book_L2`) or an exchange-native channel.
+    #[must_use]
+    pub fn supports(&self, data_type: &str) -> bool {
+        self.data_types
+            .iter()
+            .chain(&self.available_channels)
+            .any(|available| available == data_type)
+    }
+}
+
+/// The number of elements of a JSON array, counted while deserializing without
+/// allocating the elements themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ArrayLen(pub usize);
+
+impl<'de> Deserialize<'de> for ArrayLen {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayLenVisitor;
+
+        impl<'de> Visitor<'de> for ArrayLenVisitor {
+            type Value = ArrayLen;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a JSON array")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut len = 0;
+                while seq.next_element::<IgnoredAny>()?.is_some() {
+                    len += 1;
+                }
+                Ok(ArrayLen(len))
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayLenVisitor)
+    }
+}
+
+/// The symbol of an instrument definition, deserialized while skipping every other field
+/// without allocating it.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct InstrumentSymbol {
+    #[serde(deserialize_with = "deserialize_uppercase")]
+    pub id: Ustr,
 }
@@ -18,8 +18,8 @@ use std::str::FromStr;
 use chrono::{DateTime, Utc};
 use nautilus_core::UnixNanos;
 use nautilus_model::{
-    identifiers::Symbol,
-    instruments::InstrumentAny,
+    identifiers::{InstrumentId, Symbol},
+    instruments::{Instrument, InstrumentAny},
     types::{Price, Quantity},
 };
 use rust_decimal::Decimal;
@@ -33,364 +33,333 @@ use super::{
 };
 use crate::{
     enums::InstrumentType,
-    parse::{normalize_instrument_id, parse_instrument_id},
+    parse::{DefaultSymbolNormalizer, RawSymbolNormalizer, SymbolNormalizer},
 };
 
-#[must_use]
+/// Parses the given Tardis instrument `info` into Nautilus instrument definitions.
+///
+/// One definition is returned for each window of the instrument `changes` history which
+/// overlaps the `start` and `end` range (UNIX nanoseconds), with `ts_event` set to the
+/// start of the window. If `ts_init` is `None`, it defaults to `ts_event`.
+///
+/// When symbols are normalized, Bybit instrument IDs carry the product category suffix
+/// (e.g. `BTCUSDT-LINEAR`), as the same raw symbol is listed in several categories (see
+/// [`bybit_product_category`](crate::parse::bybit_product_category)).
+///
+/// # Errors
+///
+/// Returns an error if a field required for the instrument type is missing, or if a
+/// value is invalid for a Nautilus instrument.
 pub fn parse_instrument_any(
-    info: InstrumentInfo,
+    info: &InstrumentInfo,
     start: Option<u64>,
     end: Option<u64>,
     ts_init: Option<UnixNanos>,
     normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
+) -> anyhow::Result<Vec<InstrumentAny>> {
+    parse_instrument_any_with_normalizer(
+        info,
+        start,
+        end,
+        ts_init,
+        symbol_normalizer(normalize_symbols),
+    )
+}
+
+/// Parses the given Tardis instrument `info` into Nautilus instrument definitions, with
+/// the instrument ID symbols mapped by the given `normalizer`.
+///
+/// See [`parse_instrument_any`].
+///
+/// # Errors
+///
+/// Returns an error if a field required for the instrument type is missing, or if a
+/// value is invalid for a Nautilus instrument.
+pub fn parse_instrument_any_with_normalizer(
+    info: &InstrumentInfo,
+    start: Option<u64>,
+    end: Option<u64>,
+    ts_init: Option<UnixNanos>,
+    normalizer: &dyn SymbolNormalizer,
+) -> anyhow::Result<Vec<InstrumentAny>> {
     match info.instrument_type {
-        InstrumentType::Spot => parse_spot_instrument(info, start, end, ts_init, normalize_symbols),
-        InstrumentType::Perpetual => {
-            parse_perp_instrument(info, start, end, ts_init, normalize_symbols)
-        }
+        InstrumentType::Spot => parse_spot_instrument(info, start, end, ts_init, normalizer),
+        InstrumentType::Perpetual => parse_perp_instrument(info, start, end, ts_init, normalizer),
         InstrumentType::Future | InstrumentType::Combo => {
-            parse_future_instrument(info, start, end, ts_init, normalize_symbols)
-        }
-        InstrumentType::Option => {
-            parse_option_instrument(info, start, end, ts_init, normalize_symbols)
+            parse_future_instrument(info, start, end, ts_init, normalizer)
         }
+        InstrumentType::Option => parse_option_instrument(info, start, end, ts_init, normalizer),
     }
 }
 
-fn parse_spot_instrument(
-    info: InstrumentInfo,
-    start: Option<u64>,
-    end: Option<u64>,
-    ts_init: Option<UnixNanos>,
-    normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
-    let instrument_id = if normalize_symbols {
-        normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
+/// Returns the built-in symbol normalizer if `normalize_symbols` is true, otherwise a
+/// normalizer which keeps the raw symbols.
+#[must_use]
+pub fn symbol_normalizer(normalize_symbols: bool) -> &'static dyn SymbolNormalizer {
+    if normalize_symbols {
+        &DefaultSymbolNormalizer
     } else {
-        parse_instrument_id(&info.exchange, info.id)
-    };
-    let raw_symbol = Symbol::new(info.id);
-    let price_increment = get_price_increment(info.price_increment);
-    let size_increment = get_size_increment(info.amount_increment);
-    let margin_init = dec!(0); // TBD
-    let margin_maint = dec!(0); // TBD
-    let maker_fee =
-        Decimal::from_str(info.maker_fee.to_string().as_str()).expect("Invalid decimal value");
-    let taker_fee =
-        Decimal::from_str(info.taker_fee.to_string().as_str()).expect("Invalid decimal value");
+        &RawSymbolNormalizer
+    }
+}
+
+/// Returns the Nautilus instrument ID for the given `info`, with the symbol mapped by the
+/// given `normalizer`.
+pub(crate) fn parse_info_instrument_id(
+    info: &InstrumentInfo,
+    normalizer: &dyn SymbolNormalizer,
+) -> InstrumentId {
+    let symbol = normalizer.normalize(
+        info.id.as_str(),
+        &info.exchange,
+        &info.instrument_type,
+        info.inverse,
+    );
+    InstrumentId::new(symbol, info.exchange.as_venue())
+}
+
+/// The instrument specification which applied over a window of time.
+#[derive(Debug, Clone, PartialEq)]
+struct SpecWindow {
+    price_increment: Decimal,
+    amount_increment: Decimal,
+    contract_multiplier: Option<Decimal>,
+    /// UNIX timestamp (nanoseconds) when the window started.
+    ts_start: u64,
+    /// UNIX timestamp (nanoseconds) when the window ended (exclusive).
+    ts_end: u64,
+}
 
-    // Filters
+/// Returns the specification windows of the instrument which overlap the `start` and `end`
+/// range, ordered by time.
+///
+/// Each Tardis change entry holds the specification which applied *until* its `until`
+/// timestamp, so the windows are bounded by consecutive `until` values, with the current
+/// specification of `info` applying after the last change.
+fn spec_windows(info: &InstrumentInfo, start: Option<u64>, end: Option<u64>) -> Vec<SpecWindow> {
     let start = start.unwrap_or(0);
     let end = end.unwrap_or(u64::MAX);
 
-    let mut instruments: Vec<InstrumentAny> = Vec::new();
-
-    if let Some(changes) = &info.changes {
-        for change in changes {
-            let until_ns = change.until.timestamp_nanos_opt().unwrap() as u64;
-            if until_ns < start || until_ns > end {
-                continue;
-            }
+    let mut changes = info.changes.clone().unwrap_or_default();
+    changes.sort_by_key(|change| change.until);
+
+    let mut windows = Vec::with_capacity(changes.len() + 1);
+    let mut ts_start = parse_datetime_to_unix_nanos(Some(info.available_since)).as_u64();
+
+    for change in changes {
+        let ts_end = parse_datetime_to_unix_nanos(Some(change.until)).as_u64();
+        windows.push(SpecWindow {
+            price_increment: change.price_increment.unwrap_or(info.price_increment),
+            amount_increment: change.amount_increment.unwrap_or(info.amount_increment),
+            contract_multiplier: change.contract_multiplier.or(info.contract_multiplier),
+            ts_start,
+            ts_end,
+        });
+        ts_start = ts_end;
+    }
 
-            let price_increment =
-                get_price_increment(change.price_increment.unwrap_or(info.price_increment));
-            let size_increment =
-                get_size_increment(change.amount_increment.unwrap_or(info.amount_increment));
-            let ts_event = UnixNanos::from(until_ns);
+    windows.push(SpecWindow {
+        price_increment: info.price_increment,
+        amount_increment: info.amount_increment,
+        contract_multiplier: info.contract_multiplier,
+        ts_start,
+        ts_end: u64::MAX,
+    });
+
+    windows
+        .into_iter()
+        .filter(|window| window.ts_start <= end && window.ts_end > start)
+        .collect()
+}
 
-            instruments.push(create_currency_pair(
-                &info,
+fn parse_spot_instrument(
+    info: &InstrumentInfo,
+    start: Option<u64>,
+    end: Option<u64>,
+    ts_init: Option<UnixNanos>,
+    normalizer: &dyn SymbolNormalizer,
+) -> anyhow::Result<Vec<InstrumentAny>> {
+    let instrument_id = parse_info_instrument_id(info, normalizer);
+    let raw_symbol = Symbol::new(info.id);
+    let margin_init = dec!(0); // TBD
+    let margin_maint = dec!(0); // TBD
+    let maker_fee = info.maker_fee;
+    let taker_fee = info.taker_fee;
+
+    spec_windows(info, start, end)
+        .into_iter()
+        .map(|window| {
+            let ts_event = UnixNanos::from(window.ts_start);
+            create_currency_pair(
+                info,
                 instrument_id,
                 raw_symbol,
-                price_increment,
-                size_increment,
+                get_price_increment(window.price_increment)?,
+                get_size_increment(window.amount_increment)?,
                 margin_init,
                 margin_maint,
                 maker_fee,
                 taker_fee,
                 ts_event,
                 ts_init.unwrap_or(ts_event),
-            ));
-        }
-    }
-
-    let ts_init = ts_init.unwrap_or_default();
-    instruments.push(create_currency_pair(
-        &info,
-        instrument_id,
-        raw_symbol,
-        price_increment,
-        size_increment,
-        margin_init,
-        margin_maint,
-        maker_fee,
-        taker_fee,
-        ts_init,
-        ts_init,
-    ));
-
-    instruments
+            )
+        })
+        .collect()
 }
 
 fn parse_perp_instrument(
-    info: InstrumentInfo,
+    info: &InstrumentInfo,
     start: Option<u64>,
     end: Option<u64>,
     ts_init: Option<UnixNanos>,
-    normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
-    let instrument_id = if normalize_symbols {
-        normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
-    } else {
-        parse_instrument_id(&info.exchange, info.id)
-    };
+    normalizer: &dyn SymbolNormalizer,
+) -> anyhow::Result<Vec<InstrumentAny>> {
+    let instrument_id = parse_info_instrument_id(info, normalizer);
     let raw_symbol = Symbol::new(info.id);
-    let price_increment = get_price_increment(info.price_increment);
-    let size_increment = get_size_increment(info.amount_increment);
-    let multiplier = get_multiplier(info.contract_multiplier);
     let margin_init = dec!(0); // TBD
     let margin_maint = dec!(0); // TBD
-    let maker_fee =
-        Decimal::from_str(info.maker_fee.to_string().as_str()).expect("Invalid decimal value");
-    let taker_fee =
-        Decimal::from_str(info.taker_fee.to_string().as_str()).expect("Invalid decimal value");
-
-    // Filters
-    let start = start.unwrap_or(0);
-    let end = end.unwrap_or(u64::MAX);
-    let mut instruments = Vec::new();
-
-    if let Some(changes) = &info.changes {
-        for change in changes {
-            let until_ns = change.until.timestamp_nanos_opt().unwrap() as u64;
-            if until_ns < start || until_ns > end {
-                continue;
-            }
-
-            let price_increment =
-                get_price_increment(change.price_increment.unwrap_or(info.price_increment));
-            let size_increment =
-                get_size_increment(change.amount_increment.unwrap_or(info.amount_increment));
-            let multiplier = get_multiplier(info.contract_multiplier);
-            let ts_event = UnixNanos::from(until_ns);
-
-            instruments.push(create_crypto_perpetual(
-                &info,
+    let maker_fee = info.maker_fee;
+    let taker_fee = info.taker_fee;
+
+    spec_windows(info, start, end)
+        .into_iter()
+        .map(|window| {
+            let ts_event = UnixNanos::from(window.ts_start);
+            create_crypto_perpetual(
+                info,
                 instrument_id,
                 raw_symbol,
-                price_increment,
-                size_increment,
-                multiplier,
+                get_price_increment(window.price_increment)?,
+                get_size_increment(window.amount_increment)?,
+                get_multiplier(window.contract_multiplier)?,
                 margin_init,
                 margin_maint,
                 maker_fee,
                 taker_fee,
                 ts_event,
                 ts_init.unwrap_or(ts_event),
-            ));
-        }
-    }
-
-    let ts_init = ts_init.unwrap_or_default();
-    instruments.push(create_crypto_perpetual(
-        &info,
-        instrument_id,
-        raw_symbol,
-        price_increment,
-        size_increment,
-        multiplier,
-        margin_init,
-        margin_maint,
-        maker_fee,
-        taker_fee,
-        ts_init,
-        ts_init,
-    ));
-
-    instruments
+            )
+        })
+        .collect()
 }
 
 fn parse_future_instrument(
-    info: InstrumentInfo,
+    info: &InstrumentInfo,
     start: Option<u64>,
     end: Option<u64>,
     ts_init: Option<UnixNanos>,
-    normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
-    let instrument_id = if normalize_symbols {
-        normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
-    } else {
-        parse_instrument_id(&info.exchange, info.id)
-    };
+    normalizer: &dyn SymbolNormalizer,
+) -> anyhow::Result<Vec<InstrumentAny>> {
+    let instrument_id = parse_info_instrument_id(info, normalizer);
     let raw_symbol = Symbol::new(info.id);
-    let price_increment = get_price_increment(info.price_increment);
-    let size_increment = get_size_increment(info.amount_increment);
-    let multiplier = get_multiplier(info.contract_multiplier);
     let activation = parse_datetime_to_unix_nanos(Some(info.available_since));
     let expiration = parse_datetime_to_unix_nanos(info.expiry);
     let margin_init = dec!(0); // TBD
     let margin_maint = dec!(0); // TBD
-    let maker_fee =
-        Decimal::from_str(info.maker_fee.to_string().as_str()).expect("Invalid decimal value");
-    let taker_fee =
-        Decimal::from_str(info.taker_fee.to_string().as_str()).expect("Invalid decimal value");
-
-    // Filters
-    let start = start.unwrap_or(0);
-    let end = end.unwrap_or(u64::MAX);
-    let mut instruments = Vec::new();
-
-    if let Some(changes) = &info.changes {
-        for change in changes {
-            let until_ns = change.until.timestamp_nanos_opt().unwrap() as u64;
-            if until_ns < start || until_ns > end {
-                continue;
-            }
-
-            let price_increment =
-                get_price_increment(change.price_increment.unwrap_or(info.price_increment));
-            let size_increment =
-                get_size_increment(change.amount_increment.unwrap_or(info.amount_increment));
-            let multiplier = get_multiplier(info.contract_multiplier);
-            let ts_event = UnixNanos::from(until_ns);
-
-            instruments.push(create_crypto_future(
-                &info,
+    let maker_fee = info.maker_fee;
+    let taker_fee = info.taker_fee;
+
+    spec_windows(info, start, end)
+        .into_iter()
+        .map(|window| {
+            let ts_event = UnixNanos::from(window.ts_start);
+            create_crypto_future(
+                info,
                 instrument_id,
                 raw_symbol,
                 activation,
                 expiration,
-                price_increment,
-                size_increment,
-                multiplier,
+                get_price_increment(window.price_increment)?,
+                get_size_increment(window.amount_increment)?,
+                get_multiplier(window.contract_multiplier)?,
                 margin_init,
                 margin_maint,
                 maker_fee,
                 taker_fee,
                 ts_event,
                 ts_init.unwrap_or(ts_event),
-            ));
-        }
-    }
-
-    let ts_init = ts_init.unwrap_or_default();
-    instruments.push(create_crypto_future(
-        &info,
-        instrument_id,
-        raw_symbol,
-        activation,
-        expiration,
-        price_increment,
-        size_increment,
-        multiplier,
-        margin_init,
-        margin_maint,
-        maker_fee,
-        taker_fee,
-        ts_init,
-        ts_init,
-    ));
-
-    instruments
+            )
+        })
+        .collect()
 }
 
 fn parse_option_instrument(
-    info: InstrumentInfo,
+    info: &InstrumentInfo,
     start: Option<u64>,
     end: Option<u64>,
     ts_init: Option<UnixNanos>,
-    normalize_symbols: bool,
-) -> Vec<InstrumentAny> {
-    let instrument_id = if normalize_symbols {
-        normalize_instrument_id(&info.exchange, info.id, &info.instrument_type, info.inverse)
-    } else {
-        parse_instrument_id(&info.exchange, info.id)
-    };
+    normalizer: &dyn SymbolNormalizer,
+) -> anyhow::Result<Vec<InstrumentAny>> {
+    let instrument_id = parse_info_instrument_id(info, normalizer);
     let raw_symbol = Symbol::new(info.id);
     let activation = parse_datetime_to_unix_nanos(Some(info.available_since));
     let expiration = parse_datetime_to_unix_nanos(info.expiry);
-    let price_increment = get_price_increment(info.price_increment);
-    let multiplier = get_multiplier(info.contract_multiplier);
     let margin_init = dec!(0); // TBD
     let margin_maint = dec!(0); // TBD
-    let maker_fee =
-        Decimal::from_str(info.maker_fee.to_string().as_str()).expect("Invalid decimal value");
-    let taker_fee =
-        Decimal::from_str(info.taker_fee.to_string().as_str()).expect("Invalid decimal value");
-
-    // Filters
-    let start = start.unwrap_or(0);
-    let end = end.unwrap_or(u64::MAX);
-    let mut instruments = Vec::new();
-
-    if let Some(changes) = &info.changes {
-        for change in changes {
-            let until_ns = change.until.timestamp_nanos_opt().unwrap() as u64;
-            if until_ns < start || until_ns > end {
-                continue;
-            }
-
-            let price_increment =
-                get_price_increment(change.price_increment.unwrap_or(info.price_increment));
-            let multiplier = get_multiplier(info.contract_multiplier);
-            let ts_event = UnixNanos::from(until_ns);
-
-            instruments.push(create_option_contract(
-                &info,
+    let maker_fee = info.maker_fee;
+    let taker_fee = info.taker_fee;
+
+    spec_windows(info, start, end)
+        .into_iter()
+        .map(|window| {
+            let ts_event = UnixNanos::from(window.ts_start);
+            create_option_contract(
+                info,
                 instrument_id,
                 raw_symbol,
                 activation,
                 expiration,
-                price_increment,
-                multiplier,
+                get_price_increment(window.price_increment)?,
+                get_multiplier(window.contract_multiplier)?,
                 margin_init,
                 margin_maint,
                 maker_fee,
                 taker_fee,
                 ts_event,
                 ts_init.unwrap_or(ts_event),
-            ));
-        }
-    }
-
-    let ts_init = ts_init.unwrap_or_default();
-    instruments.push(create_option_contract(
-        &info,
-        instrument_id,
-        raw_symbol,
-        activation,
-        expiration,
-        price_increment,
-        multiplier,
-        margin_init,
-        margin_maint,
-        maker_fee,
-        taker_fee,
-        ts_init,
-        ts_init,
-    ));
-
-    instruments
+            )
+        })
+        .collect()
 }
 
-/// Returns the price increment from the given `value`.
-fn get_price_increment(value: f64) -> Price {
-    Price::from(value.to_string())
+/// Returns the price increment from the given `value`, with the precision of its
+/// decimal representation (ignoring trailing zeros).
+fn get_price_increment(value: Decimal) -> anyhow::Result<Price> {
+    Price::from_str(&value.normalize().to_string()).map_err(anyhow::Error::msg)
 }
 
-/// Returns the size increment from the given `value`.
-fn get_size_increment(value: f64) -> Quantity {
-    Quantity::from(value.to_string())
+/// Returns the size increment from the given `value`, with the precision of its
+/// decimal representation (ignoring trailing zeros).
+fn get_size_increment(value: Decimal) -> anyhow::Result<Quantity> {
+    Quantity::from_str(&value.normalize().to_string()).map_err(anyhow::Error::msg)
 }
 
-fn get_multiplier(value: Option<f64>) -> Option<Quantity> {
-    value.map(|x| Quantity::from(x.to_string()))
+fn get_multiplier(value: Option<Decimal>) -> anyhow::Result<Option<Quantity>> {
+    value
+        .map(|x| Quantity::from_str(&x.normalize().to_string()).map_err(anyhow::Error::msg))
+        .transpose()
 }
 
 /// Parses the given RFC 3339 datetime string (UTC) into a `UnixNanos` timestamp.
 /// If `value` is `None`, then defaults to the UNIX epoch (0 nanoseconds).
-fn parse_datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> UnixNanos {
+/// Borrows the underlying instrument of `instrument` as a trait object, for the
+/// [`Instrument`] accessors (such as `ts_event`) which [`InstrumentAny`] does not expose.
+pub(crate) fn as_instrument(instrument: &InstrumentAny) -> &dyn Instrument {
+    match instrument {
+        InstrumentAny::Betting(inst) => inst,
+        InstrumentAny::BinaryOption(inst) => inst,
+        InstrumentAny::CryptoFuture(inst) => inst,
+        InstrumentAny::CryptoPerpetual(inst) => inst,
+        InstrumentAny::CurrencyPair(inst) => inst,
+        InstrumentAny::Equity(inst) => inst,
+        InstrumentAny::FuturesContract(inst) => inst,
+        InstrumentAny::FuturesSpread(inst) => inst,
+        InstrumentAny::OptionContract(inst) => inst,
+        InstrumentAny::OptionSpread(inst) => inst,
+    }
+}
+
+pub(crate) fn parse_datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> UnixNanos {
     value
         .map(|dt| UnixNanos::from(dt.timestamp_nanos_opt().unwrap_or(0) as u64))
         .unwrap_or_default()
@@ -401,18 +370,26 @@ fn parse_datetime_to_unix_nanos(value: Option<DateTime<Utc>>) -> UnixNanos {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_model::{identifiers::InstrumentId, types::Currency};
+    use std::time::Duration;
+
+    use nautilus_model::{enums::OptionKind, identifiers::InstrumentId, types::Currency};
     use rstest::rstest;
 
     use super::*;
-    use crate::tests::load_test_json;
+    use crate::{
+        enums::{BybitProductCategory, Exchange},
+        http::models::TradingHours,
+        parse::{bybit_product_category, parse_instrument_id},
+        tests::load_test_json,
+    };
 
     #[rstest]
     fn test_parse_instrument_spot() {
         let json_data = load_test_json("instrument_spot.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
-        let instrument = parse_instrument_any(info, None, None, Some(UnixNanos::default()), false)
+        let instrument = parse_instrument_any(&info, None, None, Some(UnixNanos::default()), false)
+            .unwrap()
             .first()
             .unwrap()
             .clone();
@@ -444,7 +421,8 @@ mod tests {
         let json_data = load_test_json("instrument_perpetual.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
-        let instrument = parse_instrument_any(info, None, None, Some(UnixNanos::default()), false)
+        let instrument = parse_instrument_any(&info, None, None, Some(UnixNanos::default()), false)
+            .unwrap()
             .first()
             .unwrap()
             .clone();
@@ -476,7 +454,8 @@ mod tests {
         let json_data = load_test_json("instrument_future.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
-        let instrument = parse_instrument_any(info, None, None, Some(UnixNanos::default()), false)
+        let instrument = parse_instrument_any(&info, None, None, Some(UnixNanos::default()), false)
+            .unwrap()
             .first()
             .unwrap()
             .clone();
@@ -514,7 +493,8 @@ mod tests {
         let json_data = load_test_json("instrument_combo.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
-        let instrument = parse_instrument_any(info, None, None, Some(UnixNanos::default()), false)
+        let instrument = parse_instrument_any(&info, None, None, Some(UnixNanos::default()), false)
+            .unwrap()
             .first()
             .unwrap()
             .clone();
@@ -555,7 +535,8 @@ mod tests {
         let json_data = load_test_json("instrument_option.json");
         let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
 
-        let instrument = parse_instrument_any(info, None, None, Some(UnixNanos::default()), false)
+        let instrument = parse_instrument_any(&info, None, None, Some(UnixNanos::default()), false)
+            .unwrap()
             .first()
             .unwrap()
             .clone();
@@ -593,4 +574,374 @@ mod tests {
         // assert_eq!(instrument.maker_fee(), dec!(0.0003));  // TODO: Implement fees
         // assert_eq!(instrument.taker_fee(), dec!(0.0003));  // TODO: Implement fees
     }
+
+    #[rstest]
+    #[case("put", "200000", OptionKind::Put)]
+    #[case("call", "95000.5", OptionKind::Call)]
+    fn test_parse_instrument_option_kind_and_strike(
+        #[case] option_type: &str,
+        #[case] strike_price: &str,
+        #[case] expected_kind: OptionKind,
+    ) {
+        let mut json_data: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_option.json")).unwrap();
+        json_data["optionType"] = option_type.into();
+        json_data["strikePrice"] = strike_price.parse::<f64>().unwrap().into();
+        let info: InstrumentInfo = serde_json::from_value(json_data).unwrap();
+
+        let instruments = parse_instrument_any(&info, None, None, None, false).unwrap();
+
+        let InstrumentAny::OptionContract(option) = &instruments[0] else {
+            panic!("Expected `OptionContract`, was {:?}", instruments[0]);
+        };
+        assert_eq!(option.option_kind, expected_kind);
+        assert_eq!(
+            option.strike_price.as_f64(),
+            strike_price.parse::<f64>().unwrap()
+        );
+        assert_eq!(option.underlying.as_str(), "BTC");
+        assert_eq!(
+            option.expiration_ns,
+            UnixNanos::from(1745568000000000000) // 2025-04-25T08:00:00Z
+        );
+    }
+
+    #[rstest]
+    fn test_parse_instrument_option_missing_fields_errors() {
+        let json_data = load_test_json("instrument_option.json");
+        let mut info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
+        info.strike_price = None;
+
+        let err = parse_instrument_any(&info, None, None, None, false)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("`strike_price`"), "{err}");
+    }
+
+    fn ts_event(instrument: &InstrumentAny) -> UnixNanos {
+        as_instrument(instrument).ts_event()
+    }
+
+    fn ts_init(instrument: &InstrumentAny) -> UnixNanos {
+        as_instrument(instrument).ts_init()
+    }
+
+    /// Returns the contract multiplier of a perpetual (`Instrument::multiplier` is always 1).
+    fn perpetual_multiplier(instrument: &InstrumentAny) -> Quantity {
+        match instrument {
+            InstrumentAny::CryptoPerpetual(perpetual) => perpetual.multiplier,
+            other => panic!("Expected a perpetual, was {other:?}"),
+        }
+    }
+
+    #[rstest]
+    fn test_parse_instrument_changes() {
+        let json_data = load_test_json("instrument_perpetual_changes.json");
+        let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
+
+        let instruments = parse_instrument_any(&info, None, None, None, false).unwrap();
+
+        assert_eq!(instruments.len(), 3);
+        assert_eq!(instruments[0].price_increment(), Price::from("1"));
+        assert_eq!(perpetual_multiplier(&instruments[0]), Quantity::from(10));
+        // 2019-03-30
+        assert_eq!(
+            ts_event(&instruments[0]),
+            UnixNanos::from(1553904000000000000)
+        );
+        assert_eq!(instruments[1].price_increment(), Price::from("0.5"));
+        assert_eq!(perpetual_multiplier(&instruments[1]), Quantity::from(10));
+        // 2020-01-01
+        assert_eq!(
+            ts_event(&instruments[1]),
+            UnixNanos::from(1577836800000000000)
+        );
+        assert_eq!(instruments[2].price_increment(), Price::from("0.5"));
+        assert_eq!(perpetual_multiplier(&instruments[2]), Quantity::from(1));
+        // 2024-01-01
+        assert_eq!(
+            ts_event(&instruments[2]),
+            UnixNanos::from(1704067200000000000)
+        );
+        assert_eq!(ts_init(&instruments[2]), ts_event(&instruments[2]));
+    }
+
+    #[rstest]
+    #[case(None, None, vec![1553904000000000000, 1577836800000000000, 1704067200000000000])]
+    #[case(Some(1600000000000000000), None, vec![1577836800000000000, 1704067200000000000])]
+    #[case(None, Some(1600000000000000000), vec![1553904000000000000, 1577836800000000000])]
+    #[case(Some(1600000000000000000), Some(1650000000000000000), vec![1577836800000000000])]
+    fn test_parse_instrument_changes_within_range(
+        #[case] start: Option<u64>,
+        #[case] end: Option<u64>,
+        #[case] expected: Vec<u64>,
+    ) {
+        let json_data = load_test_json("instrument_perpetual_changes.json");
+        let info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
+
+        let instruments =
+            parse_instrument_any(&info, start, end, Some(UnixNanos::from(1)), false).unwrap();
+
+        let ts_events: Vec<u64> = instruments.iter().map(|i| ts_event(i).as_u64()).collect();
+        assert_eq!(ts_events, expected);
+        assert!(instruments.iter().all(|i| ts_init(i) == UnixNanos::from(1)));
+    }
+
+    #[rstest]
+    fn test_parse_instrument_missing_field_errors() {
+        let json_data = load_test_json("instrument_perpetual.json");
+        let mut info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
+        info.inverse = None;
+
+        let result = parse_instrument_any(&info, None, None, None, false);
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("`inverse`"), "{err}");
+    }
+
+    #[rstest]
+    #[case(
+        "instrument_spot.json",
+        Exchange::BybitSpot,
+        "BTCUSDT",
+        None,
+        BybitProductCategory::Spot,
+        "BTCUSDT-SPOT.BYBIT"
+    )]
+    #[case(
+        "instrument_perpetual.json",
+        Exchange::Bybit,
+        "BTCUSDT",
+        Some(false),
+        BybitProductCategory::Linear,
+        "BTCUSDT-LINEAR.BYBIT"
+    )]
+    #[case(
+        "instrument_perpetual.json",
+        Exchange::Bybit,
+        "BTCUSD",
+        Some(true),
+        BybitProductCategory::Inverse,
+        "BTCUSD-INVERSE.BYBIT"
+    )]
+    #[case(
+        "instrument_future.json",
+        Exchange::Bybit,
+        "BTCUSDT-14FEB25",
+        Some(false),
+        BybitProductCategory::Linear,
+        "BTCUSDT-14FEB25-LINEAR.BYBIT"
+    )]
+    #[case(
+        "instrument_future.json",
+        Exchange::Bybit,
+        "BTCUSDH25",
+        Some(true),
+        BybitProductCategory::Inverse,
+        "BTCUSDH25-INVERSE.BYBIT"
+    )]
+    #[case(
+        "instrument_option.json",
+        Exchange::BybitOptions,
+        "BTC-25APR25-200000-P",
+        None,
+        BybitProductCategory::Option,
+        "BTC-25APR25-200000-P-OPTION.BYBIT"
+    )]
+    fn test_parse_instrument_bybit_category(
+        #[case] file: &str,
+        #[case] exchange: Exchange,
+        #[case] symbol: &str,
+        #[case] inverse: Option<bool>,
+        #[case] category: BybitProductCategory,
+        #[case] expected_id: &str,
+    ) {
+        let mut info: InstrumentInfo = serde_json::from_str(&load_test_json(file)).unwrap();
+        info.exchange = exchange;
+        info.id = symbol.into();
+        if inverse.is_some() {
+            info.inverse = inverse;
+        }
+
+        let instruments = parse_instrument_any(&info, None, None, None, true).unwrap();
+
+        assert_eq!(info.bybit_category(), Some(category));
+        let instrument = instruments.last().unwrap();
+        assert_eq!(instrument.id(), InstrumentId::from(expected_id));
+        assert_eq!(instrument.raw_symbol(), Symbol::from(symbol));
+        assert_eq!(bybit_product_category(&instrument.id()), Some(category));
+    }
+
+    #[rstest]
+    fn test_parse_instrument_bybit_raw_symbol() {
+        let mut info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        info.exchange = Exchange::Bybit;
+        info.id = "BTCUSDT".into();
+        info.inverse = Some(false);
+
+        let instruments = parse_instrument_any(&info, None, None, None, false).unwrap();
+
+        let instrument_id = instruments.last().unwrap().id();
+        assert_eq!(instrument_id, InstrumentId::from("BTCUSDT.BYBIT"));
+        assert_eq!(
+            instrument_id,
+            parse_instrument_id(&Exchange::Bybit, "BTCUSDT".into())
+        );
+        assert_eq!(bybit_product_category(&instrument_id), None);
+    }
+
+    #[rstest]
+    fn test_parse_instrument_non_bybit_has_no_category() {
+        let info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+
+        let instruments = parse_instrument_any(&info, None, None, None, false).unwrap();
+
+        assert_eq!(info.bybit_category(), None);
+        assert_eq!(instruments[0].id(), InstrumentId::from("XBTUSD.BITMEX"));
+        assert_eq!(bybit_product_category(&instruments[0].id()), None);
+    }
+
+    #[rstest]
+    fn test_parse_instrument_invalid_value_errors() {
+        let json_data = load_test_json("instrument_spot.json");
+        let mut info: InstrumentInfo = serde_json::from_str(&json_data).unwrap();
+        info.price_increment = Decimal::from_i128_with_scale(10_i128.pow(21), 0); // Exceeds the maximum price
+
+        assert!(parse_instrument_any(&info, None, None, None, false).is_err());
+    }
+
+    #[rstest]
+    fn test_parse_instrument_string_increments() {
+        let numeric: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+        let string: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_spot_string_increments.json"))
+                .unwrap();
+
+        assert_eq!(string.price_increment, numeric.price_increment);
+        assert_eq!(string.amount_increment, numeric.amount_increment);
+        assert_eq!(string.min_trade_amount, numeric.min_trade_amount);
+        assert_eq!(
+            string.changes.as_ref().unwrap()[0].price_increment,
+            Some(dec!(0.01))
+        );
+        assert_eq!(
+            parse_instrument_any(&string, None, None, None, false).unwrap(),
+            parse_instrument_any(&numeric, None, None, None, false).unwrap()
+        );
+    }
+
+    #[rstest]
+    #[case(serde_json::json!(0.001))]
+    #[case(serde_json::json!("0.001"))]
+    fn test_parse_instrument_increment_has_no_float_drift(#[case] increment: serde_json::Value) {
+        let mut json_data: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        json_data["priceIncrement"] = increment.clone();
+        json_data["amountIncrement"] = increment;
+        let info: InstrumentInfo = serde_json::from_value(json_data).unwrap();
+
+        let instrument = parse_instrument_any(&info, None, None, None, false)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(info.price_increment, dec!(0.001));
+        assert_eq!(instrument.price_increment(), Price::from("0.001"));
+        assert_eq!(instrument.price_increment().precision, 3);
+        assert_eq!(instrument.size_increment(), Quantity::from("0.001"));
+        assert_eq!(instrument.size_increment().precision, 3);
+    }
+
+    #[rstest]
+    #[case(serde_json::json!("2025-02-14T08:00:00.000Z"))]
+    #[case(serde_json::json!("2025-02-14T08:00:00"))]
+    #[case(serde_json::json!(1_739_520_000_000_u64))]
+    fn test_parse_future_expiry_representations(#[case] expiry: serde_json::Value) {
+        let mut json_data: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_future.json")).unwrap();
+        json_data["expiry"] = expiry;
+        let info: InstrumentInfo = serde_json::from_value(json_data).unwrap();
+
+        let instrument = parse_instrument_any(&info, None, None, None, false)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(
+            instrument.expiration_ns(),
+            Some(UnixNanos::from(1739520000000000000))
+        );
+    }
+
+    #[rstest]
+    #[case("instrument_perpetual_funding.json", Some(Duration::from_secs(8 * 60 * 60)))]
+    #[case("instrument_perpetual.json", None)]
+    fn test_parse_funding_rate_interval(#[case] file: &str, #[case] expected: Option<Duration>) {
+        let info: InstrumentInfo = serde_json::from_str(&load_test_json(file)).unwrap();
+
+        assert_eq!(info.funding_rate_interval, expected);
+        assert!(!info.extra.contains_key("fundingRateInterval"));
+        assert!(matches!(
+            parse_instrument_any(&info, None, None, None, false).unwrap()[0],
+            InstrumentAny::CryptoPerpetual(_)
+        ));
+    }
+
+    #[rstest]
+    #[case("2025-02-03T14:30:00Z", true)] // Monday open
+    #[case("2025-02-03T20:59:59Z", true)]
+    #[case("2025-02-03T21:00:00Z", false)] // Monday close
+    #[case("2025-02-08T15:00:00Z", false)] // Saturday
+    #[case("2025-02-09T23:30:00Z", true)] // Sunday session spanning midnight
+    #[case("2025-02-10T00:30:00Z", true)]
+    #[case("2025-02-10T01:00:00Z", false)]
+    fn test_parse_trading_hours_sessions(#[case] time: &str, #[case] expected: bool) {
+        let info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_future_sessions.json")).unwrap();
+
+        let TradingHours::Sessions(sessions) = &info.trading_hours else {
+            panic!("Expected sessions, was {:?}", info.trading_hours);
+        };
+        assert_eq!(sessions.len(), 2);
+        assert!(!info.extra.contains_key("tradingHours"));
+        assert_eq!(info.trading_hours.is_open(time.parse().unwrap()), expected);
+        assert!(!parse_instrument_any(&info, None, None, None, false)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(serde_json::Value::Null))]
+    #[case(Some(serde_json::json!([])))]
+    fn test_parse_trading_hours_continuous(#[case] trading_hours: Option<serde_json::Value>) {
+        let mut json_data: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+        if let Some(trading_hours) = trading_hours {
+            json_data["tradingHours"] = trading_hours;
+        }
+        let info: InstrumentInfo = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(info.trading_hours, TradingHours::Continuous);
+        assert!(info
+            .trading_hours
+            .is_open("2025-02-08T03:00:00Z".parse().unwrap()));
+    }
+
+    #[rstest]
+    fn test_parse_instrument_extra_fields() {
+        let mut json_data: serde_json::Value =
+            serde_json::from_str(&load_test_json("instrument_perpetual.json")).unwrap();
+        json_data["fundingInterval"] = serde_json::json!(28_800);
+        let info: InstrumentInfo = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(info.extra["fundingInterval"], serde_json::json!(28_800));
+        assert!(!info.extra.contains_key("priceIncrement"));
+        assert!(!parse_instrument_any(&info, None, None, None, false)
+            .unwrap()
+            .is_empty());
+    }
 }
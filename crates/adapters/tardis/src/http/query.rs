@@ -13,24 +13,521 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_builder::Builder;
-use serde::Serialize;
+use serde::{Serialize, Serializer};
+
+use nautilus_model::{
+    enums::InstrumentClass, identifiers::InstrumentId, instruments::InstrumentAny,
+};
+
+use super::{client::InstrumentPredicate, models::InstrumentInfo};
+use crate::{
+    enums::{BybitProductCategory, ContractType, InstrumentType},
+    parse::bybit_product_category,
+};
+
+/// The listing status of instruments to request.
+///
+/// Tardis only applies the `active` filter when present, so [`ActiveFilter::All`] is
+/// serialized by omitting the field, returning both active and inactive instruments.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActiveFilter {
+    /// Only instruments which are currently listed (`"active": true`).
+    Active,
+    /// Only instruments which are no longer listed (`"active": false`).
+    Inactive,
+    /// Both active and inactive instruments (`active` omitted).
+    #[default]
+    All,
+}
+
+impl ActiveFilter {
+    /// Returns whether the filter includes all instruments regardless of listing status.
+    #[must_use]
+    pub const fn is_all(&self) -> bool {
+        matches!(self, Self::All)
+    }
+}
+
+impl From<Option<bool>> for ActiveFilter {
+    fn from(value: Option<bool>) -> Self {
+        match value {
+            Some(true) => Self::Active,
+            Some(false) => Self::Inactive,
+            None => Self::All,
+        }
+    }
+}
+
+impl Serialize for ActiveFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Active => serializer.serialize_bool(true),
+            Self::Inactive => serializer.serialize_bool(false),
+            Self::All => serializer.serialize_none(),
+        }
+    }
+}
 
 /// Provides an instrument metadata API filter object.
 ///
+/// Tardis matches currency codes case-sensitively against its normalized uppercase codes
+/// (e.g. `BTC`), so the base and quote currencies are uppercased when serialized.
+///
+/// An empty list (such as `Some(vec![])`) is treated as no filter and omitted when
+/// serialized, as Tardis may otherwise match no instruments.
+///
 /// See <https://docs.tardis.dev/api/instruments-metadata-api>.
-#[derive(Debug, Default, Serialize, Builder)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Builder)]
+#[builder(build_fn(validate = "Self::validate"))]
 #[serde(rename_all = "camelCase")]
 pub struct InstrumentFilter {
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "is_none_or_empty")]
+    #[serde(serialize_with = "serialize_uppercase")]
     pub base_currency: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "is_none_or_empty")]
+    #[serde(serialize_with = "serialize_uppercase")]
     pub quote_currency: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The instrument types to include as API strings (see [`InstrumentType`]).
+    #[serde(skip_serializing_if = "is_none_or_empty")]
     #[serde(rename = "type")]
     pub instrument_type: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The contract types to include as API strings (see [`ContractType`]).
+    #[serde(skip_serializing_if = "is_none_or_empty")]
     pub contract_type: Option<Vec<String>>,
+    /// Which instruments to include by listing status (all instruments by default).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "ActiveFilter::is_all")]
+    pub active: ActiveFilter,
+    /// Only include instruments with these exchange symbols.
+    ///
+    /// The metadata API has no symbol filter, so this is not serialized: instead each
+    /// symbol is requested from the single instrument endpoint.
+    #[builder(default)]
+    #[serde(skip)]
+    pub symbols: Option<Vec<String>>,
+    /// Only include instruments settled in these currencies (e.g. `USDT` for linear or
+    /// `BTC` for inverse contracts).
+    ///
+    /// The metadata API has no settlement currency filter, so this is not serialized:
+    /// instead it is applied to the parsed instruments.
+    #[builder(default)]
+    #[serde(skip)]
+    pub settlement_currency: Option<Vec<String>>,
+    /// Only include Bybit instruments in these product categories (e.g. `Linear` for
+    /// USDT-margined contracts).
+    ///
+    /// The metadata API has no product category filter, so this is not serialized:
+    /// instead it is applied to the parsed instruments, keeping those of other venues.
+    #[builder(default)]
+    #[serde(skip)]
+    pub bybit_category: Option<Vec<BybitProductCategory>>,
+    /// Only include instruments available since this date (ISO 8601).
+    #[builder(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
+    pub available_since: Option<DateTime<Utc>>,
+    /// Only include instruments available up to this date (ISO 8601).
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_to: Option<DateTime<Utc>>,
+}
+
+impl InstrumentFilter {
+    /// Returns the names of the filter fields which the Tardis instrument `info` does not
+    /// match, which is empty if it matches.
+    ///
+    /// Only fields present on [`InstrumentInfo`] are checked: the base and quote currencies,
+    /// instrument type and listing status (the contract type and dates are not).
+    #[must_use]
+    pub fn mismatched_fields(&self, info: &InstrumentInfo) -> Vec<&'static str> {
+        let matches = |values: &Option<Vec<String>>, value: &str| {
+            values.as_ref().is_none_or(|values| {
+                values.is_empty() || values.iter().any(|v| v.eq_ignore_ascii_case(value))
+            })
+        };
+
+        let mut fields = Vec::new();
+        if !matches(&self.base_currency, &info.base_currency) {
+            fields.push("base_currency");
+        }
+        if !matches(&self.quote_currency, &info.quote_currency) {
+            fields.push("quote_currency");
+        }
+        if !matches(&self.instrument_type, &info.instrument_type.to_string()) {
+            fields.push("instrument_type");
+        }
+        let active_matches = match self.active {
+            ActiveFilter::Active => info.active,
+            ActiveFilter::Inactive => !info.active,
+            ActiveFilter::All => true,
+        };
+        if !active_matches {
+            fields.push("active");
+        }
+        fields
+    }
+
+    /// Returns the symbols to request individually, or `None` if the symbols filter is
+    /// unset or empty.
+    #[must_use]
+    pub fn requested_symbols(&self) -> Option<&[String]> {
+        self.symbols
+            .as_deref()
+            .filter(|symbols| !symbols.is_empty())
+    }
+
+    /// Returns whether an instrument settled in `currency` passes the settlement currency
+    /// filter (currency codes are matched case-insensitively).
+    #[must_use]
+    pub fn matches_settlement_currency(&self, currency: &str) -> bool {
+        self.settlement_currency.as_ref().is_none_or(|currencies| {
+            currencies.is_empty()
+                || currencies
+                    .iter()
+                    .any(|code| code.eq_ignore_ascii_case(currency))
+        })
+    }
+
+    /// Returns whether the instrument with `instrument_id` passes the Bybit product category
+    /// filter (instruments of other venues always pass).
+    #[must_use]
+    pub fn matches_bybit_category(&self, instrument_id: &InstrumentId) -> bool {
+        let Some(categories) = &self.bybit_category else {
+            return true;
+        };
+        bybit_product_category(instrument_id)
+            .is_none_or(|category| categories.is_empty() || categories.contains(&category))
+    }
+}
+
+impl InstrumentFilterBuilder {
+    /// Sets the instrument types to include.
+    ///
+    /// Use [`Self::instrument_type`] with raw API strings for types not yet known to
+    /// this adapter.
+    pub fn instrument_types(
+        &mut self,
+        instrument_types: impl IntoIterator<Item = InstrumentType>,
+    ) -> &mut Self {
+        self.instrument_type(Some(
+            instrument_types
+                .into_iter()
+                .map(|t| t.to_string())
+                .collect(),
+        ))
+    }
+
+    /// Sets the contract types to include.
+    ///
+    /// Use [`Self::contract_type`] with raw API strings for types not yet known to
+    /// this adapter.
+    pub fn contract_types(
+        &mut self,
+        contract_types: impl IntoIterator<Item = ContractType>,
+    ) -> &mut Self {
+        self.contract_type(Some(
+            contract_types.into_iter().map(|t| t.to_string()).collect(),
+        ))
+    }
+
+    /// Sets `available_since` to the given `duration` before now, such as the last 30 days
+    /// for a recurring job, resolved to an absolute date when called.
+    pub fn available_since_relative(&mut self, duration: Duration) -> &mut Self {
+        let since = TimeDelta::from_std(duration)
+            .ok()
+            .and_then(|delta| Utc::now().checked_sub_signed(delta))
+            .unwrap_or(DateTime::<Utc>::MIN_UTC);
+        self.available_since(Some(since))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if let (Some(Some(since)), Some(Some(to))) = (self.available_since, self.available_to) {
+            if since > to {
+                return Err(format!(
+                    "`available_since` {since} was after `available_to` {to}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Options for requesting Nautilus instrument definitions, applied by the client rather
+/// than sent to Tardis.
+///
+/// See [`TardisHttpClient::instruments`](super::TardisHttpClient::instruments).
+#[derive(Clone, Default)]
+pub struct InstrumentOptions {
+    /// Only include instruments of these Nautilus instrument classes.
+    pub classes: Option<Vec<InstrumentClass>>,
+    /// Only include instruments for which the predicate returns true (such as a tick size
+    /// or multiplier range).
+    pub predicate: Option<Arc<InstrumentPredicate>>,
+    /// Overrides the client setting for normalizing symbols.
+    pub normalize_symbols: Option<bool>,
+    /// Overrides the client timeout for each request.
+    pub timeout: Option<Duration>,
+}
+
+impl Debug for InstrumentOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(InstrumentOptions))
+            .field("classes", &self.classes)
+            .field("predicate", &self.predicate.is_some())
+            .field("normalize_symbols", &self.normalize_symbols)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl InstrumentOptions {
+    /// Returns whether the parsed `instrument` passes the classes and predicate options.
+    #[must_use]
+    pub fn matches(&self, instrument: &InstrumentAny) -> bool {
+        self.classes
+            .as_ref()
+            .is_none_or(|classes| classes.contains(&instrument.instrument_class()))
+            && self
+                .predicate
+                .as_ref()
+                .is_none_or(|predicate| predicate(instrument))
+    }
+}
+
+fn is_none_or_empty<T>(values: &Option<Vec<T>>) -> bool {
+    values.as_ref().is_none_or(Vec::is_empty)
+}
+
+fn serialize_uppercase<S: Serializer>(
+    values: &Option<Vec<String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    values
+        .as_ref()
+        .map(|values| {
+            values
+                .iter()
+                .map(|value| value.to_uppercase())
+                .collect::<Vec<_>>()
+        })
+        .serialize(serializer)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::tests::load_test_json;
+
+    #[rstest]
+    fn test_serialize_date_range() {
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(None)
+            .quote_currency(None)
+            .instrument_type(Some(vec!["future".to_string()]))
+            .contract_type(None)
+            .available_since(Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()))
+            .available_to(Some(Utc.with_ymd_and_hms(2024, 6, 30, 0, 0, 0).unwrap()))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&filter).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":["future"],"availableSince":"2024-01-01T00:00:00Z","availableTo":"2024-06-30T00:00:00Z"}"#
+        );
+    }
+
+    #[rstest]
+    fn test_available_since_relative() {
+        let duration = Duration::from_secs(30 * 24 * 60 * 60);
+        let window_start = Utc::now() - TimeDelta::from_std(duration).unwrap();
+
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(None)
+            .quote_currency(None)
+            .instrument_type(None)
+            .contract_type(None)
+            .available_since_relative(duration)
+            .build()
+            .unwrap();
+
+        let window_end = Utc::now() - TimeDelta::from_std(duration).unwrap();
+        let since = filter.available_since.unwrap();
+        assert!(since >= window_start && since <= window_end);
+    }
+
+    #[rstest]
+    fn test_builder_rejects_inverted_date_range() {
+        let result = InstrumentFilterBuilder::default()
+            .base_currency(None)
+            .quote_currency(None)
+            .instrument_type(None)
+            .contract_type(None)
+            .available_since(Some(Utc.with_ymd_and_hms(2024, 6, 30, 0, 0, 0).unwrap()))
+            .available_to(Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()))
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_serialize_currencies_uppercased() {
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(Some(vec!["btc".to_string(), "Eth".to_string()]))
+            .quote_currency(Some(vec!["usdt".to_string()]))
+            .instrument_type(None)
+            .contract_type(None)
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&filter).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"baseCurrency":["BTC","ETH"],"quoteCurrency":["USDT"]}"#
+        );
+    }
+
+    #[rstest]
+    fn test_serialize_typed_instrument_and_contract_types() {
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(None)
+            .quote_currency(None)
+            .instrument_types([InstrumentType::Perpetual, InstrumentType::Future])
+            .contract_types([ContractType::LinearPerpetual, ContractType::InverseFuture])
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&filter).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"type":["perpetual","future"],"contractType":["linear_perpetual","inverse_future"]}"#
+        );
+    }
+
+    #[rstest]
+    fn test_serialize_empty_vectors_omitted() {
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(Some(vec![]))
+            .quote_currency(Some(vec![]))
+            .instrument_type(Some(vec![]))
+            .contract_type(Some(vec![]))
+            .build()
+            .unwrap();
+
+        let json = serde_json::to_string(&filter).unwrap();
+
+        assert_eq!(json, "{}");
+    }
+
+    #[rstest]
+    fn test_mismatched_fields() {
+        let info: InstrumentInfo =
+            serde_json::from_str(&load_test_json("instrument_spot.json")).unwrap();
+        let matching = InstrumentFilter {
+            base_currency: Some(vec!["btc".to_string()]),
+            instrument_type: Some(vec!["spot".to_string()]),
+            active: ActiveFilter::Active,
+            ..Default::default()
+        };
+        let mismatching = InstrumentFilter {
+            base_currency: Some(vec!["ETH".to_string()]),
+            quote_currency: Some(vec!["USDC".to_string()]),
+            active: ActiveFilter::Inactive,
+            ..Default::default()
+        };
+
+        assert!(matching.mismatched_fields(&info).is_empty());
+        assert_eq!(
+            mismatching.mismatched_fields(&info),
+            vec!["base_currency", "active"]
+        );
+    }
+
+    #[rstest]
+    #[case(ActiveFilter::Active, r#"{"active":true}"#)]
+    #[case(ActiveFilter::Inactive, r#"{"active":false}"#)]
+    #[case(ActiveFilter::All, "{}")]
+    fn test_serialize_active_filter(#[case] active: ActiveFilter, #[case] expected: &str) {
+        let filter = InstrumentFilter {
+            active,
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&filter).unwrap();
+
+        assert_eq!(json, expected);
+    }
+
+    #[rstest]
+    #[case(None, "USDT", true)]
+    #[case(Some(vec![]), "USDT", true)]
+    #[case(Some(vec!["usdt"]), "USDT", true)]
+    #[case(Some(vec!["BTC", "ETH"]), "ETH", true)]
+    #[case(Some(vec!["BTC"]), "USDT", false)]
+    fn test_matches_settlement_currency(
+        #[case] settlement_currency: Option<Vec<&str>>,
+        #[case] currency: &str,
+        #[case] expected: bool,
+    ) {
+        let filter = InstrumentFilterBuilder::default()
+            .base_currency(None)
+            .quote_currency(None)
+            .instrument_type(None)
+            .contract_type(None)
+            .settlement_currency(
+                settlement_currency
+                    .map(|codes| codes.into_iter().map(ToString::to_string).collect()),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(filter.matches_settlement_currency(currency), expected);
+        assert_eq!(serde_json::to_string(&filter).unwrap(), "{}");
+    }
+
+    #[rstest]
+    #[case(None, "BTCUSDT-SPOT.BYBIT", true)]
+    #[case(Some(vec![BybitProductCategory::Linear]), "BTCUSDT-LINEAR.BYBIT", true)]
+    #[case(Some(vec![BybitProductCategory::Linear]), "BTCUSDT-SPOT.BYBIT", false)]
+    #[case(Some(vec![BybitProductCategory::Inverse]), "BTCUSD-PERP.BINANCE", true)]
+    fn test_matches_bybit_category(
+        #[case] bybit_category: Option<Vec<BybitProductCategory>>,
+        #[case] instrument_id: &str,
+        #[case] expected: bool,
+    ) {
+        let filter = InstrumentFilter {
+            bybit_category,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            filter.matches_bybit_category(&InstrumentId::from(instrument_id)),
+            expected
+        );
+        assert_eq!(serde_json::to_string(&filter).unwrap(), "{}");
+    }
+
+    #[rstest]
+    #[case(Some(true), ActiveFilter::Active)]
+    #[case(Some(false), ActiveFilter::Inactive)]
+    #[case(None, ActiveFilter::All)]
+    fn test_active_filter_from_option(#[case] value: Option<bool>, #[case] expected: ActiveFilter) {
+        assert_eq!(ActiveFilter::from(value), expected);
+    }
 }
@@ -0,0 +1,344 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Client-side tracking of the Tardis rate-limit budget, and optional client-side caps
+//! on the request rate and the number of concurrent requests.
+
+use std::{
+    num::{NonZeroU32, NonZeroUsize},
+    sync::Mutex,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use tokio::sync::{Semaphore, SemaphorePermit};
+
+pub const HEADER_RATELIMIT_REMAINING: &str = "x-ratelimit-remaining";
+pub const HEADER_RATELIMIT_RESET: &str = "x-ratelimit-reset";
+
+/// Reset values below this threshold are interpreted as seconds from now rather than
+/// as a UNIX timestamp (seconds).
+const RESET_EPOCH_THRESHOLD_SECS: u64 = 1_000_000_000;
+
+#[derive(Debug, Clone, Copy)]
+struct RateLimitBudget {
+    remaining: u32,
+    reset_at: Option<Instant>,
+}
+
+/// The rate-limit budget reported by the Tardis API response headers.
+///
+/// The state is shared between all clones of a client, so concurrent requests
+/// observe the same budget.
+#[derive(Debug, Default)]
+pub struct RateLimitState {
+    budget: Mutex<Option<RateLimitBudget>>,
+}
+
+impl RateLimitState {
+    /// Updates the budget from the given response `headers`.
+    ///
+    /// Responses without a remaining header leave the current budget unchanged.
+    pub fn update(&self, headers: &HeaderMap) {
+        let Some(remaining) = parse_header::<u32>(headers, HEADER_RATELIMIT_REMAINING) else {
+            return;
+        };
+        let reset_at = parse_header::<u64>(headers, HEADER_RATELIMIT_RESET).map(reset_instant);
+
+        let mut budget = self.budget.lock().expect("Rate limit mutex poisoned");
+        *budget = Some(RateLimitBudget {
+            remaining,
+            reset_at,
+        });
+    }
+
+    /// Returns the number of remaining requests last reported by the API (if known).
+    #[must_use]
+    pub fn remaining(&self) -> Option<u32> {
+        self.budget
+            .lock()
+            .expect("Rate limit mutex poisoned")
+            .map(|b| b.remaining)
+    }
+
+    /// Returns how long to wait before the next request, if the budget is exhausted.
+    #[must_use]
+    pub fn wait_duration(&self) -> Option<Duration> {
+        let budget = (*self.budget.lock().expect("Rate limit mutex poisoned"))?;
+        if budget.remaining > 0 {
+            return None;
+        }
+
+        let now = Instant::now();
+        budget
+            .reset_at
+            .filter(|reset_at| *reset_at > now)
+            .map(|reset_at| reset_at - now)
+    }
+}
+
+/// The maximum random jitter added to a token bucket wait, as a fraction of the interval
+/// between tokens.
+const TOKEN_BUCKET_JITTER_FRACTION: f64 = 0.1;
+
+#[derive(Debug)]
+struct TokenBucketState {
+    /// The available tokens, negative when requests are waiting for future tokens.
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// A token bucket capping the rate of requests sent by a client.
+///
+/// The bucket holds up to one second of tokens, so short bursts are allowed while the
+/// sustained rate stays under the cap. The state is shared between all clones of a client.
+#[derive(Debug)]
+pub struct TokenBucket {
+    requests_per_second: NonZeroU32,
+    state: Mutex<TokenBucketState>,
+}
+
+impl TokenBucket {
+    /// Creates a new [`TokenBucket`] instance, starting full.
+    #[must_use]
+    pub fn new(requests_per_second: NonZeroU32) -> Self {
+        Self {
+            requests_per_second,
+            state: Mutex::new(TokenBucketState {
+                tokens: f64::from(requests_per_second.get()),
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Returns the sustained rate of requests allowed.
+    #[must_use]
+    pub const fn requests_per_second(&self) -> NonZeroU32 {
+        self.requests_per_second
+    }
+
+    /// Takes a token, returning how long to wait before sending the request.
+    ///
+    /// When the bucket is empty, the next future token is reserved, so concurrent
+    /// requests are spaced out in the order they arrive. Non-zero waits include a small
+    /// random jitter, so that clients started together do not send in lockstep.
+    #[must_use]
+    pub fn acquire(&self) -> Duration {
+        let requests_per_second = f64::from(self.requests_per_second.get());
+        let mut state = self.state.lock().expect("Token bucket mutex poisoned");
+        let now = Instant::now();
+        let elapsed = now
+            .saturating_duration_since(state.updated_at)
+            .as_secs_f64();
+        state.tokens = elapsed
+            .mul_add(requests_per_second, state.tokens)
+            .min(requests_per_second);
+        state.updated_at = now;
+        state.tokens -= 1.0;
+
+        if state.tokens >= 0.0 {
+            return Duration::ZERO;
+        }
+
+        let interval = 1.0 / requests_per_second;
+        let jitter = rand::thread_rng().gen_range(0.0..=interval * TOKEN_BUCKET_JITTER_FRACTION);
+        Duration::from_secs_f64(-state.tokens * interval + jitter)
+    }
+}
+
+/// A cap on the number of requests a client has in flight at once.
+///
+/// Every request holds a permit while it is sent, and the permits are shared between all
+/// clones of a client.
+#[derive(Debug)]
+pub struct ConcurrencyLimit {
+    max_concurrent_requests: NonZeroUsize,
+    semaphore: Semaphore,
+}
+
+impl ConcurrencyLimit {
+    /// Creates a new [`ConcurrencyLimit`] instance.
+    #[must_use]
+    pub fn new(max_concurrent_requests: NonZeroUsize) -> Self {
+        Self {
+            max_concurrent_requests,
+            semaphore: Semaphore::new(max_concurrent_requests.get()),
+        }
+    }
+
+    /// Returns the maximum number of requests in flight at once.
+    #[must_use]
+    pub const fn max_concurrent_requests(&self) -> NonZeroUsize {
+        self.max_concurrent_requests
+    }
+
+    /// Waits for a permit to send a request, which is released when dropped.
+    pub async fn acquire(&self) -> SemaphorePermit<'_> {
+        self.semaphore
+            .acquire()
+            .await
+            .expect("Concurrency limit semaphore closed")
+    }
+}
+
+/// Parses the `Retry-After` header from the given response `headers`.
+///
+/// Both the delay-seconds and HTTP-date forms are supported.
+#[must_use]
+pub fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let retry_at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((retry_at - Utc::now()).to_std().unwrap_or_default())
+}
+
+fn parse_header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn reset_instant(value: u64) -> Instant {
+    let delay = if value < RESET_EPOCH_THRESHOLD_SECS {
+        Duration::from_secs(value)
+    } else {
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Duration::from_secs(value.saturating_sub(now_secs))
+    };
+    Instant::now() + delay
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn headers(remaining: &str, reset: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            HEADER_RATELIMIT_REMAINING,
+            HeaderValue::from_str(remaining).unwrap(),
+        );
+        headers.insert(
+            HEADER_RATELIMIT_RESET,
+            HeaderValue::from_str(reset).unwrap(),
+        );
+        headers
+    }
+
+    #[rstest]
+    fn test_no_budget_by_default() {
+        let state = RateLimitState::default();
+        assert_eq!(state.remaining(), None);
+        assert_eq!(state.wait_duration(), None);
+    }
+
+    #[rstest]
+    fn test_remaining_budget_does_not_wait() {
+        let state = RateLimitState::default();
+        state.update(&headers("10", "30"));
+
+        assert_eq!(state.remaining(), Some(10));
+        assert_eq!(state.wait_duration(), None);
+    }
+
+    #[rstest]
+    fn test_exhausted_budget_waits_until_reset() {
+        let state = RateLimitState::default();
+        state.update(&headers("0", "30"));
+
+        let wait = state.wait_duration().unwrap();
+        assert_eq!(state.remaining(), Some(0));
+        assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+    }
+
+    #[rstest]
+    fn test_exhausted_budget_with_epoch_reset() {
+        let now_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let state = RateLimitState::default();
+        state.update(&headers("0", &(now_secs + 60).to_string()));
+
+        let wait = state.wait_duration().unwrap();
+        assert!(wait > Duration::from_secs(58) && wait <= Duration::from_secs(60));
+    }
+
+    #[rstest]
+    #[case("120", Some(Duration::from_secs(120)))]
+    #[case("Wed, 21 Oct 2015 07:28:00 GMT", Some(Duration::ZERO))]
+    #[case("invalid", None)]
+    fn test_parse_retry_after(#[case] value: &str, #[case] expected: Option<Duration>) {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_str(value).unwrap());
+
+        assert_eq!(parse_retry_after(&headers), expected);
+    }
+
+    #[rstest]
+    fn test_parse_retry_after_http_date_in_future() {
+        let retry_at = Utc::now() + chrono::Duration::seconds(60);
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_str(&retry_at.to_rfc2822()).unwrap(),
+        );
+
+        let delay = parse_retry_after(&headers).unwrap();
+        assert!(delay > Duration::from_secs(58) && delay <= Duration::from_secs(60));
+    }
+
+    #[rstest]
+    fn test_missing_headers_leave_budget_unchanged() {
+        let state = RateLimitState::default();
+        state.update(&headers("5", "30"));
+        state.update(&HeaderMap::new());
+
+        assert_eq!(state.remaining(), Some(5));
+    }
+
+    #[rstest]
+    fn test_token_bucket_allows_burst_then_spaces_requests() {
+        let bucket = TokenBucket::new(NonZeroU32::new(4).unwrap());
+
+        for _ in 0..4 {
+            assert_eq!(bucket.acquire(), Duration::ZERO);
+        }
+
+        // Each further request reserves the next token (250ms apart), plus jitter
+        let first_wait = bucket.acquire();
+        let second_wait = bucket.acquire();
+        assert!(
+            first_wait > Duration::from_millis(240) && first_wait <= Duration::from_millis(280)
+        );
+        assert!(
+            second_wait > Duration::from_millis(490) && second_wait <= Duration::from_millis(530)
+        );
+    }
+}
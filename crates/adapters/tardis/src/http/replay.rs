@@ -0,0 +1,185 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Types for replaying historical market data with the Tardis HTTP API.
+//!
+//! The API serves the raw exchange messages of each minute of a day from the
+//! `/data-feeds/{exchange}` endpoint, as lines of a local timestamp followed by the
+//! message JSON. See <https://docs.tardis.dev/api/http#data-feeds-exchange>.
+
+use chrono::{DateTime, Duration, DurationRound, NaiveDate, Timelike, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::error::{Error, Result};
+use crate::enums::Exchange;
+
+/// A filter selecting an exchange channel (data type), and optionally its symbols.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplayFilter {
+    /// The exchange-native channel, such as `trade` for `BitMEX`.
+    pub channel: String,
+    /// The exchange-native symbols, or `None` for every symbol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbols: Option<Vec<String>>,
+}
+
+impl ReplayFilter {
+    /// Creates a new [`ReplayFilter`] instance.
+    #[must_use]
+    pub fn new(channel: impl Into<String>, symbols: Option<Vec<String>>) -> Self {
+        Self {
+            channel: channel.into(),
+            symbols,
+        }
+    }
+}
+
+/// A request to replay the historical messages of an exchange over a date range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayRequest {
+    /// The exchange to replay.
+    pub exchange: Exchange,
+    /// The channels and symbols to replay (every channel when empty).
+    pub filters: Vec<ReplayFilter>,
+    /// The start of the range (inclusive).
+    pub from: DateTime<Utc>,
+    /// The end of the range (exclusive).
+    pub to: DateTime<Utc>,
+}
+
+impl ReplayRequest {
+    /// Creates a new [`ReplayRequest`] instance.
+    #[must_use]
+    pub const fn new(
+        exchange: Exchange,
+        filters: Vec<ReplayFilter>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            exchange,
+            filters,
+            from,
+            to,
+        }
+    }
+
+    /// Returns the date and minute offset within the day of each minute of the range, as
+    /// requested from the API.
+    #[must_use]
+    pub fn minutes(&self) -> Vec<(NaiveDate, u32)> {
+        let mut minute = self
+            .from
+            .duration_trunc(Duration::minutes(1))
+            .unwrap_or(self.from);
+        let mut minutes = Vec::new();
+        while minute < self.to {
+            let offset = minute.hour() * 60 + minute.minute();
+            minutes.push((minute.date_naive(), offset));
+            minute += Duration::minutes(1);
+        }
+        minutes
+    }
+
+    /// Returns whether `timestamp` is within the range.
+    #[must_use]
+    pub fn contains(&self, timestamp: DateTime<Utc>) -> bool {
+        timestamp >= self.from && timestamp < self.to
+    }
+}
+
+/// A raw exchange message replayed from the Tardis HTTP API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayMessage {
+    /// When the message was received by Tardis.
+    pub local_timestamp: DateTime<Utc>,
+    /// The exchange-native message.
+    pub message: Value,
+}
+
+/// Parses a `line` of a data feed response, returning `None` for an empty line (which
+/// marks a disconnect of the exchange connection used to collect the data).
+///
+/// # Errors
+///
+/// Returns an error if the line is not a timestamp followed by a JSON message.
+pub fn parse_replay_line(line: &str) -> Result<Option<ReplayMessage>> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+
+    let parse_error = |source| Error::ResponseParse {
+        status: 200,
+        source,
+        body_snippet: line.chars().take(128).collect(),
+    };
+    let (timestamp, message) = line.split_once(' ').unwrap_or((line, ""));
+    let local_timestamp =
+        serde_json::from_value(Value::String(timestamp.to_string())).map_err(parse_error)?;
+    let message = serde_json::from_str(message).map_err(parse_error)?;
+
+    Ok(Some(ReplayMessage {
+        local_timestamp,
+        message,
+    }))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_minutes_spans_days() {
+        let request = ReplayRequest::new(
+            Exchange::Bitmex,
+            vec![],
+            "2019-05-01T23:58:30Z".parse().unwrap(),
+            "2019-05-02T00:01:00Z".parse().unwrap(),
+        );
+
+        let day = NaiveDate::from_ymd_opt(2019, 5, 1).unwrap();
+        assert_eq!(
+            request.minutes(),
+            vec![(day, 1438), (day, 1439), (day.succ_opt().unwrap(), 0)]
+        );
+    }
+
+    #[rstest]
+    fn test_parse_replay_line() {
+        let message = parse_replay_line(
+            r#"2019-05-01T00:00:00.1234567Z {"table":"trade","action":"insert"}"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            message.local_timestamp.timestamp_nanos_opt(),
+            Some(1_556_668_800_123_456_700)
+        );
+        assert_eq!(message.message["table"], "trade");
+        assert!(parse_replay_line("").unwrap().is_none());
+        assert!(matches!(
+            parse_replay_line("2019-05-01T00:00:00Z {"),
+            Err(Error::ResponseParse { .. })
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Retry configuration for the Tardis HTTP client.
+//!
+//! Failed requests are retried with exponential backoff and jitter, but only for
+//! failures which are likely to be transient (rate limiting, server errors and timeouts).
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use nautilus_network::backoff::ExponentialBackoff;
+
+/// The HTTP status codes which are considered transient and therefore retryable.
+pub const RETRYABLE_STATUS_CODES: [u16; 5] = [429, 500, 502, 503, 504];
+
+const BACKOFF_FACTOR: f64 = 2.0;
+
+/// The retry configuration for a [`TardisHttpClient`](super::TardisHttpClient).
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub base_delay: Duration,
+    /// The maximum delay between retries.
+    pub max_delay: Duration,
+}
+
+impl RetryConfig {
+    /// Creates a new [`RetryConfig`] instance.
+    #[must_use]
+    pub const fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    /// Returns a new backoff for a single request, with jitter of up to half the base delay.
+    #[must_use]
+    pub fn backoff(&self) -> ExponentialBackoff {
+        let jitter_ms = (self.base_delay.as_millis() / 2) as u64;
+        ExponentialBackoff::new(
+            self.base_delay,
+            self.max_delay,
+            BACKOFF_FACTOR,
+            jitter_ms,
+            false,
+        )
+    }
+}
+
+/// A Tardis HTTP API endpoint, for assigning retry policies per endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TardisEndpoint {
+    /// The exchanges details (`/exchanges`).
+    Exchanges,
+    /// All instruments of an exchange (`/instruments/{exchange}`), the most expensive.
+    InstrumentsInfo,
+    /// A single instrument (`/instruments/{exchange}/{symbol}`).
+    InstrumentInfo,
+}
+
+impl TardisEndpoint {
+    /// Returns the endpoint of the request `path` relative to the base URL (without the
+    /// query), or `None` for any other path.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match segments.as_slice() {
+            ["exchanges"] => Some(Self::Exchanges),
+            ["instruments", _] => Some(Self::InstrumentsInfo),
+            ["instruments", _, _] => Some(Self::InstrumentInfo),
+            _ => None,
+        }
+    }
+}
+
+/// Retry counters of a [`TardisHttpClient`](super::TardisHttpClient), shared between its
+/// clones, for tuning the retry configuration and detecting degradation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RetryStats {
+    /// The total number of request attempts, including retries.
+    pub attempts: u64,
+    /// The number of requests which were retried at least once.
+    pub retried_requests: u64,
+    /// The number of requests which failed after exhausting their retries.
+    pub exhausted_retries: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct RetryCounters {
+    attempts: AtomicU64,
+    retried_requests: AtomicU64,
+    exhausted_retries: AtomicU64,
+}
+
+impl RetryCounters {
+    /// Records an attempt, where `attempt` is the 1-based attempt number of the request.
+    pub(crate) fn on_attempt(&self, attempt: u32) {
+        self.attempts.fetch_add(1, Ordering::Relaxed);
+        if attempt == 2 {
+            self.retried_requests.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn on_exhausted(&self) {
+        self.exhausted_retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> RetryStats {
+        RetryStats {
+            attempts: self.attempts.load(Ordering::Relaxed),
+            retried_requests: self.retried_requests.load(Ordering::Relaxed),
+            exhausted_retries: self.exhausted_retries.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Returns whether the given HTTP `status` should be retried.
+#[must_use]
+pub fn is_retryable_status(status: u16) -> bool {
+    RETRYABLE_STATUS_CODES.contains(&status)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(429, true)]
+    #[case(500, true)]
+    #[case(502, true)]
+    #[case(503, true)]
+    #[case(504, true)]
+    #[case(400, false)]
+    #[case(401, false)]
+    #[case(404, false)]
+    #[case(501, false)]
+    fn test_is_retryable_status(#[case] status: u16, #[case] expected: bool) {
+        assert_eq!(is_retryable_status(status), expected);
+    }
+
+    #[rstest]
+    fn test_backoff_respects_max_delay() {
+        let config = RetryConfig::new(5, Duration::from_millis(100), Duration::from_millis(150));
+        let mut backoff = config.backoff();
+
+        let _ = backoff.next_duration();
+        let _ = backoff.next_duration();
+        assert_eq!(backoff.current_delay(), Duration::from_millis(150));
+    }
+
+    #[rstest]
+    #[case("/exchanges", Some(TardisEndpoint::Exchanges))]
+    #[case("/instruments/deribit", Some(TardisEndpoint::InstrumentsInfo))]
+    #[case(
+        "/instruments/deribit/BTC-PERPETUAL",
+        Some(TardisEndpoint::InstrumentInfo)
+    )]
+    #[case("/api-key-info", None)]
+    fn test_endpoint_from_path(#[case] path: &str, #[case] expected: Option<TardisEndpoint>) {
+        assert_eq!(TardisEndpoint::from_path(path), expected);
+    }
+
+    #[rstest]
+    fn test_retry_counters() {
+        let counters = RetryCounters::default();
+
+        counters.on_attempt(1);
+        counters.on_attempt(1);
+        counters.on_attempt(2);
+        counters.on_attempt(3);
+        counters.on_exhausted();
+
+        assert_eq!(
+            counters.stats(),
+            RetryStats {
+                attempts: 4,
+                retried_requests: 1,
+                exhausted_retries: 1,
+            }
+        );
+    }
+}
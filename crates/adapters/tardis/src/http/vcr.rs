@@ -0,0 +1,352 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Recording and playback of Tardis HTTP API interactions (VCR-style), for deterministic
+//! test fixtures which are easy to refresh against the real API.
+//!
+//! In [`VcrMode::Record`] each request and response is written to a cassette file as it is
+//! received, and in [`VcrMode::Playback`] responses are served from the cassette without
+//! any network access. Requests are matched by method and path (relative to the base URL,
+//! including the query), and request headers are never recorded, so cassettes contain no
+//! API keys.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use reqwest::{header::HeaderMap, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use super::error::{Error, Result};
+
+/// A recorded request and its response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The HTTP method of the request.
+    pub method: String,
+    /// The request path relative to the base URL, including the query.
+    pub path: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The response headers as name and value pairs.
+    pub headers: Vec<(String, String)>,
+    /// The decoded response body.
+    pub body: String,
+}
+
+/// A sequence of recorded interactions, stored as a JSON file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Loads a cassette from the JSON file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file fails to read or is not a valid cassette.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Saves the cassette as a JSON file at `path`, replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file fails to write.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)?;
+        Ok(())
+    }
+}
+
+/// Whether interactions are recorded to, or played back from, the cassette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcrMode {
+    /// Requests are sent, and each interaction is appended to the cassette file.
+    Record,
+    /// Responses are served from the cassette without sending requests.
+    Playback,
+}
+
+#[derive(Debug)]
+struct VcrState {
+    cassette: Cassette,
+    /// Whether each interaction of the cassette has been played back.
+    played: Vec<bool>,
+}
+
+/// Records interactions to, or plays them back from, a cassette file.
+#[derive(Debug)]
+pub struct Vcr {
+    mode: VcrMode,
+    path: PathBuf,
+    state: Mutex<VcrState>,
+    /// Serializes writes of the cassette file, so the last recorded state is written last.
+    save_lock: tokio::sync::Mutex<()>,
+}
+
+impl Vcr {
+    /// Creates a new [`Vcr`] instance recording to a new cassette at `path`, replacing any
+    /// existing file once the first interaction is recorded.
+    #[must_use]
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self::new(VcrMode::Record, path.into(), Cassette::default())
+    }
+
+    /// Creates a new [`Vcr`] instance playing back the cassette at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cassette fails to load.
+    pub fn playback(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette = Cassette::load(&path)?;
+        Ok(Self::new(VcrMode::Playback, path, cassette))
+    }
+
+    fn new(mode: VcrMode, path: PathBuf, cassette: Cassette) -> Self {
+        let played = vec![false; cassette.interactions.len()];
+        Self {
+            mode,
+            path,
+            state: Mutex::new(VcrState { cassette, played }),
+            save_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Returns whether interactions are recorded or played back.
+    #[must_use]
+    pub const fn mode(&self) -> VcrMode {
+        self.mode
+    }
+
+    /// Returns the path of the cassette file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns a clone of the cassette recorded or being played back.
+    #[must_use]
+    pub fn cassette(&self) -> Cassette {
+        self.state
+            .lock()
+            .expect("VCR mutex poisoned")
+            .cassette
+            .clone()
+    }
+
+    /// Records a response with the given `status`, `headers` and `body` (already read
+    /// by the caller, within its size limit) to a `method` request for `path`, writing the
+    /// cassette file, and returns an equivalent response for the caller to consume.
+    pub(crate) async fn record_response(
+        &self,
+        method: &str,
+        path: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<Response> {
+        let headers = headers
+            .iter()
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+
+        let interaction = Interaction {
+            method: method.to_string(),
+            path: path.to_string(),
+            status: status.as_u16(),
+            headers,
+            body: String::from_utf8_lossy(body).into_owned(),
+        };
+        let resp = to_response(&interaction)?;
+
+        // The file is written without holding the state mutex, so it is never held
+        // across an await point
+        let _save_guard = self.save_lock.lock().await;
+        let json = {
+            let mut state = self.state.lock().expect("VCR mutex poisoned");
+            state.cassette.interactions.push(interaction);
+            state.played.push(true);
+            serde_json::to_string_pretty(&state.cassette)?
+        };
+        tokio::fs::write(&self.path, json).await?;
+        tracing::debug!("Recorded {method} {path} to {}", self.path.display());
+
+        Ok(resp)
+    }
+
+    /// Returns the response of the first interaction for a `method` request for `path`
+    /// which has not yet been played back.
+    ///
+    /// Once every matching interaction has been played back, the last is repeated.
+    pub(crate) fn play(&self, method: &str, path: &str) -> Result<Response> {
+        let mut state = self.state.lock().expect("VCR mutex poisoned");
+        let VcrState { cassette, played } = &mut *state;
+
+        let matches =
+            |interaction: &Interaction| interaction.method == method && interaction.path == path;
+        let index = cassette
+            .interactions
+            .iter()
+            .zip(played.iter())
+            .position(|(interaction, played)| !played && matches(interaction))
+            .or_else(|| cassette.interactions.iter().rposition(matches))
+            .ok_or_else(|| {
+                Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!(
+                        "No interaction for {method} {path} in cassette {}",
+                        self.path.display()
+                    ),
+                ))
+            })?;
+
+        played[index] = true;
+        tracing::debug!("Playing back {method} {path} from {}", self.path.display());
+        to_response(&cassette.interactions[index])
+    }
+}
+
+/// Builds a response from a recorded `interaction`.
+///
+/// The body is already decoded, so any content encoding headers are dropped.
+fn to_response(interaction: &Interaction) -> Result<Response> {
+    let mut builder = ::http::Response::builder().status(interaction.status);
+    for (name, value) in &interaction.headers {
+        if name.eq_ignore_ascii_case("content-encoding")
+            || name.eq_ignore_ascii_case("content-length")
+        {
+            continue;
+        }
+        builder = builder.header(name, value);
+    }
+
+    let resp = builder
+        .body(interaction.body.clone())
+        .map_err(|e| Error::Io(std::io::Error::other(e)))?;
+    Ok(Response::from(resp))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use axum::{routing::get, Router};
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        enums::Exchange,
+        http::TardisHttpClient,
+        tests::{load_test_json, start_mock_server},
+    };
+
+    fn client(base_url: &str, vcr: Vcr) -> TardisHttpClient {
+        TardisHttpClient::new(Some("test-key"), Some(base_url), None, false)
+            .unwrap()
+            .with_vcr(Arc::new(vcr))
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_record_then_playback() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let counter_clone = counter.clone();
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(move || {
+                counter_clone.fetch_add(1, Ordering::SeqCst);
+                async { format!("[{}]", load_test_json("instrument_spot.json")) }
+            }),
+        );
+        let base_url = start_mock_server(router).await;
+        let path = std::env::temp_dir().join(format!("tardis-vcr-{}.json", uuid::Uuid::new_v4()));
+
+        let recorded = client(&base_url, Vcr::record(&path))
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        let cassette = Cassette::load(&path).unwrap();
+        assert_eq!(cassette.interactions.len(), 1);
+        assert_eq!(cassette.interactions[0].path, "/instruments/deribit");
+        // Request headers are never recorded, so the API key appears nowhere in the file
+        assert!(!fs::read_to_string(&path).unwrap().contains("test-key"));
+
+        // Played back from the cassette without a server
+        let played = client("http://127.0.0.1:9", Vcr::playback(&path).unwrap())
+            .instruments_info(Exchange::Deribit, None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(played.len(), recorded.len());
+        assert_eq!(played[0].id, recorded[0].id);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_record_response_too_large() {
+        let router = Router::new().route(
+            "/instruments/{exchange}",
+            get(|| async { format!("[{}]", load_test_json("instrument_spot.json")) }),
+        );
+        let base_url = start_mock_server(router).await;
+        let path = std::env::temp_dir().join(format!("tardis-vcr-{}.json", uuid::Uuid::new_v4()));
+
+        let result = client(&base_url, Vcr::record(&path))
+            .with_max_response_bytes(16)
+            .instruments_info(Exchange::Deribit, None, None)
+            .await;
+
+        assert!(matches!(result, Err(Error::ResponseTooLarge { limit: 16 })));
+        assert!(!path.exists());
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_playback_missing_interaction() {
+        let path = std::env::temp_dir().join(format!("tardis-vcr-{}.json", uuid::Uuid::new_v4()));
+        Cassette::default().save(&path).unwrap();
+
+        let result = client("http://127.0.0.1:9", Vcr::playback(&path).unwrap())
+            .instruments_info(Exchange::Deribit, None, None)
+            .await;
+
+        assert!(matches!(result, Err(Error::Io(_))));
+
+        fs::remove_file(path).unwrap();
+    }
+}
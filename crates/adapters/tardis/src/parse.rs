@@ -13,6 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{str::FromStr, time::Duration};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use nautilus_core::{datetime::NANOSECONDS_IN_MICROSECOND, UnixNanos};
 use nautilus_model::{
     data::BarSpecification,
@@ -20,10 +23,12 @@ use nautilus_model::{
     identifiers::{InstrumentId, Symbol},
     types::{Price, ERROR_PRICE, PRICE_MAX, PRICE_MIN},
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer};
+use strum::IntoEnumIterator;
 use ustr::Ustr;
 
-use super::enums::{Exchange, InstrumentType, OptionType};
+use super::enums::{BybitProductCategory, Exchange, InstrumentType, OptionType};
 
 pub fn deserialize_uppercase<'de, D>(deserializer: D) -> Result<Ustr, D::Error>
 where
@@ -32,6 +37,139 @@ where
     String::deserialize(deserializer).map(|s| Ustr::from(&s.to_uppercase()))
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(serde_json::Number),
+    String(String),
+}
+
+impl NumberOrString {
+    fn into_decimal<E: serde::de::Error>(self) -> Result<Decimal, E> {
+        match self {
+            // Normalized, as the representation may have trailing zeros (such as `1.0e-6`)
+            Self::Number(value) => parse_decimal(&value.to_string())
+                .map(|decimal| decimal.normalize())
+                .map_err(|_| E::custom(format!("invalid decimal '{value}'"))),
+            Self::String(value) => parse_decimal(value.trim())
+                .map_err(|_| E::custom(format!("invalid decimal '{value}'"))),
+        }
+    }
+}
+
+/// Parses a decimal from its plain (`0.001`) or scientific (`1e-8`) string representation.
+fn parse_decimal(value: &str) -> Result<Decimal, rust_decimal::Error> {
+    if value.contains(['e', 'E']) {
+        Decimal::from_scientific(value)
+    } else {
+        Decimal::from_str(value)
+    }
+}
+
+/// Deserializes a decimal from either a JSON number (`0.01`) or string (`"0.01"`), as
+/// reported for increments by different exchanges.
+///
+/// Strings are parsed exactly. Numbers are parsed from their shortest round-trip
+/// representation, which recovers the JSON literal (up to 15 significant digits) rather
+/// than the nearest binary float, so an increment such as `0.001` has no float drift.
+pub fn deserialize_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    NumberOrString::deserialize(deserializer)?.into_decimal()
+}
+
+/// Deserializes an optional decimal from either a JSON number or string, see
+/// [`deserialize_decimal`].
+pub fn deserialize_option_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<NumberOrString>::deserialize(deserializer)?
+        .map(NumberOrString::into_decimal)
+        .transpose()
+}
+
+/// Deserializes an optional interval from a number of seconds (as a JSON number or string).
+///
+/// Intervals which are not a non-negative number of seconds (such as a variable funding
+/// interval) deserialize as `None` rather than failing the whole definition.
+pub fn deserialize_option_seconds<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    let seconds = match &value {
+        Some(serde_json::Value::Number(number)) => number.as_f64(),
+        Some(serde_json::Value::String(string)) => string.trim().parse::<f64>().ok(),
+        _ => None,
+    };
+    Ok(seconds.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok()))
+}
+
+/// Parses a datetime from an ISO 8601 string, or a number of milliseconds since the UNIX
+/// epoch (as reported by some exchanges for expiries).
+///
+/// ISO 8601 datetimes without a timezone, and plain dates, are assumed to be UTC.
+///
+/// # Errors
+///
+/// Returns an error if `value` is not in either representation.
+pub fn parse_datetime(value: &str) -> anyhow::Result<DateTime<Utc>> {
+    let value = value.trim();
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return parse_epoch_millis(value.parse()?);
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Ok(datetime.with_timezone(&Utc));
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    })
+    .map(|datetime| datetime.and_utc())
+    .ok_or_else(|| anyhow::anyhow!("Invalid datetime '{value}'"))
+}
+
+fn parse_epoch_millis(millis: i64) -> anyhow::Result<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| anyhow::anyhow!("Epoch milliseconds {millis} out of range"))
+}
+
+/// Deserializes an optional datetime from an ISO 8601 string or a number of milliseconds
+/// since the UNIX epoch (as a JSON number or string), see [`parse_datetime`].
+pub fn deserialize_option_datetime<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Option::<serde_json::Value>::deserialize(deserializer)?;
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(string)) => parse_datetime(&string)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        Some(serde_json::Value::Number(number)) => number
+            .as_i64()
+            .ok_or_else(|| anyhow::anyhow!("Invalid epoch milliseconds {number}"))
+            .and_then(parse_epoch_millis)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "invalid datetime '{other}'"
+        ))),
+    }
+}
+
 #[must_use]
 #[inline]
 pub fn normalize_symbol_str(
@@ -51,21 +189,12 @@ pub fn normalize_symbol_str(
             append_suffix(symbol, "-PERP")
         }
 
-        Exchange::Bybit | Exchange::BybitSpot | Exchange::BybitOptions => match instrument_type {
-            InstrumentType::Spot => append_suffix(symbol, "-SPOT"),
-            InstrumentType::Perpetual if !is_inverse.unwrap_or(false) => {
-                append_suffix(symbol, "-LINEAR")
-            }
-            InstrumentType::Future if !is_inverse.unwrap_or(false) => {
-                append_suffix(symbol, "-LINEAR")
+        Exchange::Bybit | Exchange::BybitSpot | Exchange::BybitOptions => {
+            match BybitProductCategory::from_instrument_type(instrument_type, is_inverse) {
+                Some(category) => append_suffix(symbol, category.symbol_suffix()),
+                None => symbol,
             }
-            InstrumentType::Perpetual if is_inverse == Some(true) => {
-                append_suffix(symbol, "-INVERSE")
-            }
-            InstrumentType::Future if is_inverse == Some(true) => append_suffix(symbol, "-INVERSE"),
-            InstrumentType::Option => append_suffix(symbol, "-OPTION"),
-            _ => symbol,
-        },
+        }
 
         Exchange::Dydx if instrument_type == &InstrumentType::Perpetual => {
             append_suffix(symbol, "-PERP")
@@ -79,6 +208,101 @@ pub fn normalize_symbol_str(
     }
 }
 
+/// Returns the raw Tardis symbol for the normalized `symbol` of an instrument on
+/// `exchange`, reversing [`normalize_symbol_str`] by removing any suffix it appends.
+#[must_use]
+pub fn denormalize_symbol_str<'a>(symbol: &'a str, exchange: &Exchange) -> &'a str {
+    let suffixes: &[&str] = match exchange {
+        Exchange::Binance
+        | Exchange::BinanceFutures
+        | Exchange::BinanceUs
+        | Exchange::BinanceDex
+        | Exchange::BinanceJersey
+        | Exchange::Dydx
+        | Exchange::GateIoFutures => &["-PERP"],
+        Exchange::Bybit | Exchange::BybitSpot | Exchange::BybitOptions => {
+            &["-SPOT", "-LINEAR", "-INVERSE", "-OPTION"]
+        }
+        _ => &[],
+    };
+    suffixes
+        .iter()
+        .find_map(|suffix| symbol.strip_suffix(suffix))
+        .unwrap_or(symbol)
+}
+
+/// Returns the Bybit product category of the instrument with the given `instrument_id`,
+/// from the category suffix of its symbol, or `None` for other venues.
+///
+/// Instrument definitions parsed for Bybit carry the suffix when symbols are normalized,
+/// see [`parse_instrument_any`](crate::http::parse::parse_instrument_any).
+#[must_use]
+pub fn bybit_product_category(instrument_id: &InstrumentId) -> Option<BybitProductCategory> {
+    if instrument_id.venue != Exchange::Bybit.as_venue() {
+        return None;
+    }
+    BybitProductCategory::iter().find(|category| {
+        instrument_id
+            .symbol
+            .as_str()
+            .ends_with(category.symbol_suffix())
+    })
+}
+
+/// Maps raw Tardis symbols to the symbols of Nautilus instrument IDs.
+///
+/// Implement this to apply custom symbol conventions when parsing instrument definitions,
+/// see [`TardisHttpClient::with_symbol_normalizer`](crate::http::TardisHttpClient::with_symbol_normalizer).
+pub trait SymbolNormalizer: Send + Sync {
+    /// Returns the Nautilus symbol for the `raw` Tardis symbol of an instrument with the
+    /// given `instrument_type` (and `is_inverse` flag for derivatives) on `exchange`.
+    fn normalize(
+        &self,
+        raw: &str,
+        exchange: &Exchange,
+        instrument_type: &InstrumentType,
+        is_inverse: Option<bool>,
+    ) -> Symbol;
+}
+
+/// The built-in [`SymbolNormalizer`], which appends a suffix to symbols which are
+/// ambiguous across instrument types on a venue (see [`normalize_symbol_str`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultSymbolNormalizer;
+
+impl SymbolNormalizer for DefaultSymbolNormalizer {
+    fn normalize(
+        &self,
+        raw: &str,
+        exchange: &Exchange,
+        instrument_type: &InstrumentType,
+        is_inverse: Option<bool>,
+    ) -> Symbol {
+        Symbol::from_ustr_unchecked(normalize_symbol_str(
+            Ustr::from(raw),
+            exchange,
+            instrument_type,
+            is_inverse,
+        ))
+    }
+}
+
+/// A [`SymbolNormalizer`] which keeps the raw Tardis symbols unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RawSymbolNormalizer;
+
+impl SymbolNormalizer for RawSymbolNormalizer {
+    fn normalize(
+        &self,
+        raw: &str,
+        _exchange: &Exchange,
+        _instrument_type: &InstrumentType,
+        _is_inverse: Option<bool>,
+    ) -> Symbol {
+        Symbol::from_ustr_unchecked(Ustr::from(raw))
+    }
+}
+
 fn append_suffix(symbol: Ustr, suffix: &str) -> Ustr {
     let mut symbol = symbol.to_string();
     symbol.push_str(suffix);
@@ -207,13 +431,117 @@ pub fn bar_spec_to_tardis_trade_bar_string(bar_spec: &BarSpecification) -> Strin
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
-
     use nautilus_model::enums::AggressorSide;
     use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     use super::*;
 
+    #[derive(Deserialize)]
+    struct Increment {
+        #[serde(deserialize_with = "deserialize_decimal")]
+        value: Decimal,
+        #[serde(default, deserialize_with = "deserialize_option_decimal")]
+        optional: Option<Decimal>,
+    }
+
+    #[rstest]
+    #[case(r#"{"value": 0.01}"#, dec!(0.01), None)]
+    #[case(r#"{"value": "0.01"}"#, dec!(0.01), None)]
+    #[case(r#"{"value": 1, "optional": "1e-8"}"#, dec!(1), Some(dec!(0.00000001)))]
+    #[case(r#"{"value": "5", "optional": 0.5}"#, dec!(5), Some(dec!(0.5)))]
+    #[case(r#"{"value": 1E-9, "optional": null}"#, dec!(0.000000001), None)]
+    fn test_deserialize_decimal(
+        #[case] json: &str,
+        #[case] expected: Decimal,
+        #[case] expected_optional: Option<Decimal>,
+    ) {
+        let increment: Increment = serde_json::from_str(json).unwrap();
+        assert_eq!(increment.value, expected);
+        assert_eq!(increment.optional, expected_optional);
+    }
+
+    #[rstest]
+    #[case("0.001")]
+    #[case("0.000001")]
+    #[case("0.3")]
+    #[case("123456.789")]
+    fn test_deserialize_decimal_has_no_float_drift(#[case] literal: &str) {
+        let expected = Decimal::from_str(literal).unwrap();
+
+        for json in [
+            format!(r#"{{"value": {literal}}}"#),
+            format!(r#"{{"value": "{literal}"}}"#),
+        ] {
+            let increment: Increment = serde_json::from_str(&json).unwrap();
+            assert_eq!(increment.value, expected);
+            assert_eq!(increment.value.to_string(), literal);
+        }
+    }
+
+    #[rstest]
+    #[case(r#"{"value": "abc"}"#)]
+    #[case(r#"{"value": true}"#)]
+    fn test_deserialize_decimal_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<Increment>(json).is_err());
+    }
+
+    #[derive(Deserialize)]
+    struct Interval {
+        #[serde(default, deserialize_with = "deserialize_option_seconds")]
+        value: Option<Duration>,
+    }
+
+    #[rstest]
+    #[case(r#"{"value": 28800}"#, Some(Duration::from_secs(28_800)))]
+    #[case(r#"{"value": "3600"}"#, Some(Duration::from_secs(3_600)))]
+    #[case(r#"{"value": 0.5}"#, Some(Duration::from_millis(500)))]
+    #[case(r#"{"value": "variable"}"#, None)]
+    #[case(r#"{"value": -1}"#, None)]
+    #[case(r#"{"value": null}"#, None)]
+    #[case(r#"{}"#, None)]
+    fn test_deserialize_option_seconds(#[case] json: &str, #[case] expected: Option<Duration>) {
+        let interval: Interval = serde_json::from_str(json).unwrap();
+        assert_eq!(interval.value, expected);
+    }
+
+    #[derive(Deserialize)]
+    struct Expiry {
+        #[serde(default, deserialize_with = "deserialize_option_datetime")]
+        value: Option<DateTime<Utc>>,
+    }
+
+    #[rstest]
+    #[case(r#"{"value": "2025-02-14T08:00:00.000Z"}"#)]
+    #[case(r#"{"value": "2025-02-14T09:00:00+01:00"}"#)]
+    #[case(r#"{"value": "2025-02-14T08:00:00"}"#)]
+    #[case(r#"{"value": "2025-02-14 08:00:00.000"}"#)]
+    #[case(r#"{"value": 1739520000000}"#)]
+    #[case(r#"{"value": "1739520000000"}"#)]
+    fn test_deserialize_option_datetime(#[case] json: &str) {
+        let expiry: Expiry = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            expiry.value.unwrap().timestamp_nanos_opt(),
+            Some(1_739_520_000_000_000_000)
+        );
+    }
+
+    #[rstest]
+    #[case(r#"{"value": null}"#, true)]
+    #[case(r#"{}"#, true)]
+    #[case(r#"{"value": "14/02/2025"}"#, false)]
+    #[case(r#"{"value": true}"#, false)]
+    fn test_deserialize_option_datetime_missing_or_invalid(
+        #[case] json: &str,
+        #[case] expected_ok: bool,
+    ) {
+        let result = serde_json::from_str::<Expiry>(json);
+        assert_eq!(result.is_ok(), expected_ok);
+        if let Ok(expiry) = result {
+            assert!(expiry.value.is_none());
+        }
+    }
+
     #[rstest]
     #[case(Exchange::Binance, "ETHUSDT", "ETHUSDT.BINANCE")]
     #[case(Exchange::Bitmex, "XBTUSD", "XBTUSD.BITMEX")]
@@ -286,6 +614,26 @@ mod tests {
         assert_eq!(instrument_id, expected_instrument_id);
     }
 
+    #[rstest]
+    #[case(Exchange::BinanceFutures, "BTCUSDT", InstrumentType::Perpetual, None)]
+    #[case(Exchange::Binance, "BTCUSDT", InstrumentType::Spot, None)]
+    #[case(Exchange::Bybit, "BTCUSDT", InstrumentType::Spot, None)]
+    #[case(Exchange::Bybit, "BTCUSD", InstrumentType::Future, Some(true))]
+    #[case(Exchange::Dydx, "BTC-USD", InstrumentType::Perpetual, None)]
+    #[case(Exchange::Deribit, "BTC-PERPETUAL", InstrumentType::Perpetual, None)]
+    fn test_denormalize_symbol_round_trip(
+        #[case] exchange: Exchange,
+        #[case] symbol: Ustr,
+        #[case] instrument_type: InstrumentType,
+        #[case] is_inverse: Option<bool>,
+    ) {
+        let normalized = normalize_symbol_str(symbol, &exchange, &instrument_type, is_inverse);
+        assert_eq!(
+            denormalize_symbol_str(&normalized, &exchange),
+            symbol.as_str()
+        );
+    }
+
     #[rstest]
     #[case("bid", OrderSide::Buy)]
     #[case("ask", OrderSide::Sell)]
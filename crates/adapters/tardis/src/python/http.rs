@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use nautilus_core::python::{to_pyruntime_err, to_pyvalue_err};
 use nautilus_model::python::instruments::instrument_any_to_pyobject;
@@ -21,7 +21,10 @@ use pyo3::prelude::*;
 
 use crate::{
     enums::Exchange,
-    http::{query::InstrumentFilterBuilder, TardisHttpClient},
+    http::{
+        query::{ActiveFilter, InstrumentFilterBuilder, InstrumentOptions},
+        TardisHttpClient,
+    },
 };
 
 #[pymethods]
@@ -37,8 +40,24 @@ impl TardisHttpClient {
         Self::new(api_key, base_url, timeout_secs, normalize_symbols).map_err(to_pyruntime_err)
     }
 
+    #[pyo3(name = "ping")]
+    #[pyo3(signature = (timeout_secs=None))]
+    fn py_ping<'py>(
+        &self,
+        timeout_secs: Option<u64>,
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let timeout = timeout_secs.map(Duration::from_secs);
+        let self_clone = self.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            self_clone.ping(timeout).await.map_err(to_pyruntime_err)
+        })
+    }
+
     #[pyo3(name = "instrument")]
-    #[pyo3(signature = (exchange, symbol, start=None, end=None, ts_init=None))]
+    #[pyo3(signature = (exchange, symbol, start=None, end=None, ts_init=None, timeout_secs=None))]
+    #[allow(clippy::too_many_arguments)]
     fn py_instrument<'py>(
         &self,
         exchange: &str,
@@ -46,15 +65,20 @@ impl TardisHttpClient {
         start: Option<u64>,
         end: Option<u64>,
         ts_init: Option<u64>,
+        timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let exchange = Exchange::from_str(exchange).map_err(to_pyvalue_err)?;
         let symbol = symbol.to_owned();
+        let options = InstrumentOptions {
+            timeout: timeout_secs.map(Duration::from_secs),
+            ..Default::default()
+        };
         let self_clone = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let instruments = self_clone
-                .instrument(exchange, &symbol, start, end, ts_init)
+                .instrument(exchange, &symbol, start, end, ts_init, &options)
                 .await
                 .map_err(to_pyruntime_err)?;
 
@@ -69,7 +93,7 @@ impl TardisHttpClient {
     }
 
     #[pyo3(name = "instruments")]
-    #[pyo3(signature = (exchange, start=None, end=None, base_currency=None, quote_currency=None, instrument_type=None, contract_type=None, active=None, ts_init=None))]
+    #[pyo3(signature = (exchange, start=None, end=None, base_currency=None, quote_currency=None, instrument_type=None, contract_type=None, active=None, ts_init=None, timeout_secs=None))]
     #[allow(clippy::too_many_arguments)]
     fn py_instruments<'py>(
         &self,
@@ -82,6 +106,7 @@ impl TardisHttpClient {
         contract_type: Option<Vec<String>>,
         active: Option<bool>,
         ts_init: Option<u64>,
+        timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let exchange = Exchange::from_str(exchange).map_err(to_pyvalue_err)?;
@@ -90,15 +115,19 @@ impl TardisHttpClient {
             .quote_currency(quote_currency)
             .instrument_type(instrument_type)
             .contract_type(contract_type)
-            .active(active)
+            .active(ActiveFilter::from(active))
             .build()
-            .unwrap(); // SAFETY: Safe since all fields are Option
+            .map_err(to_pyvalue_err)?;
 
+        let options = InstrumentOptions {
+            timeout: timeout_secs.map(Duration::from_secs),
+            ..Default::default()
+        };
         let self_clone = self.clone();
 
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
             let instruments = self_clone
-                .instruments(exchange, start, end, ts_init, Some(&filter))
+                .instruments(exchange, start, end, ts_init, Some(&filter), &options)
                 .await
                 .map_err(to_pyruntime_err)?;
 
@@ -84,7 +84,7 @@ async fn gather_instruments_info(
         tracing::info!("Requesting instruments for {exchange}");
 
         async move {
-            match client.instruments_info(exchange.clone(), None).await {
+            match client.instruments_info(exchange.clone(), None, None).await {
                 Ok(instruments) => Some((exchange, instruments)),
                 Err(e) => {
                     tracing::error!("Error fetching instruments for {exchange}: {e}");
@@ -136,8 +136,8 @@ pub async fn run_tardis_machine_replay_from_config(config_filepath: &Path) -> an
     for (exchange, instruments) in &info_map {
         for inst in instruments {
             let instrument_type = inst.instrument_type.clone();
-            let price_precision = precision_from_str(&inst.price_increment.to_string());
-            let size_precision = precision_from_str(&inst.amount_increment.to_string());
+            let price_precision = precision_from_str(&inst.price_increment.normalize().to_string());
+            let size_precision = precision_from_str(&inst.amount_increment.normalize().to_string());
 
             let instrument_id = if normalize_symbols {
                 normalize_instrument_id(exchange, inst.id, &instrument_type, inst.inverse)
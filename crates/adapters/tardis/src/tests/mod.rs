@@ -26,3 +26,18 @@ pub fn load_test_json(file_name: &str) -> String {
 
     fs::read_to_string(path).expect("Failed to read test JSON file")
 }
+
+/// Starts a local HTTP server for the given `router` and returns its base URL.
+#[cfg(test)]
+pub async fn start_mock_server(router: axum::Router) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("Failed to bind mock server listener");
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, router).await.unwrap();
+    });
+
+    format!("http://{addr}")
+}
@@ -4664,7 +4664,8 @@ class TardisHttpClient:
         timeout_secs: int = 60,
         normalize_symbols: bool = True,
     ) -> None: ...
-    async def instrument(self, exchange: str, symbol: str, start: int | None = None, end: int | None = None, ts_init: int | None = None) -> list[Instrument]: ...  # noqa
+    async def ping(self, timeout_secs: int | None = None) -> None: ...
+    async def instrument(self, exchange: str, symbol: str, start: int | None = None, end: int | None = None, ts_init: int | None = None, timeout_secs: int | None = None) -> list[Instrument]: ...  # noqa
     async def instruments(
         self,
         exchange: str,
@@ -4676,6 +4677,7 @@ class TardisHttpClient:
         contract_type: list[str] | None = None,
         active: bool | None = None,
         ts_init: int | None = None,
+        timeout_secs: int | None = None,
     ) -> list[Instrument]: ...
 
 class ReplayNormalizedRequestOptions:
@@ -0,0 +1,110 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2025 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from collections.abc import Callable
+from collections.abc import Coroutine
+from typing import Any
+
+import pytest
+from aiohttp import web
+from aiohttp.test_utils import TestServer
+
+from nautilus_trader.core import nautilus_pyo3
+
+
+INSTRUMENT_SPOT = {
+    "id": "BTC_USDC",
+    "exchange": "deribit",
+    "baseCurrency": "BTC",
+    "quoteCurrency": "USDC",
+    "type": "spot",
+    "active": True,
+    "availableSince": "2023-04-24T00:00:00.000Z",
+    "priceIncrement": 0.01,
+    "amountIncrement": 0.0001,
+    "minTradeAmount": 0.0001,
+    "makerFee": 0,
+    "takerFee": 0,
+    "listing": "2023-04-24T13:00:02.000Z",
+    "datasetId": "BTC_USDC",
+}
+
+
+@pytest.fixture(name="test_server")
+async def fixture_test_server(
+    aiohttp_server: Callable[..., Coroutine[Any, Any, TestServer]],
+) -> TestServer:
+    async def api_key_info(request):
+        return web.json_response([])
+
+    async def instruments(request):
+        return web.json_response([INSTRUMENT_SPOT])
+
+    async def instrument(request):
+        return web.json_response(INSTRUMENT_SPOT)
+
+    app = web.Application()
+    app.router.add_route("GET", "/api-key-info", api_key_info)
+    app.router.add_route("GET", "/instruments/{exchange}", instruments)
+    app.router.add_route("GET", "/instruments/{exchange}/{symbol}", instrument)
+
+    server = await aiohttp_server(app)
+    return server
+
+
+def _client(server: TestServer) -> nautilus_pyo3.TardisHttpClient:
+    return nautilus_pyo3.TardisHttpClient(
+        api_key="test-key",
+        base_url=f"http://{server.host}:{server.port}",
+    )
+
+
+@pytest.mark.asyncio()
+async def test_ping(test_server: Coroutine) -> None:
+    # Arrange
+    server: TestServer = await test_server
+    client = _client(server)
+
+    # Act, Assert
+    assert await client.ping() is None
+
+
+@pytest.mark.asyncio()
+async def test_instruments(test_server: Coroutine) -> None:
+    # Arrange
+    server: TestServer = await test_server
+    client = _client(server)
+
+    # Act
+    instruments = await client.instruments("deribit")
+
+    # Assert
+    assert len(instruments) == 1
+    assert isinstance(instruments[0], nautilus_pyo3.CurrencyPair)
+    assert instruments[0].id.venue.value == "DERIBIT"
+
+
+@pytest.mark.asyncio()
+async def test_instrument(test_server: Coroutine) -> None:
+    # Arrange
+    server: TestServer = await test_server
+    client = _client(server)
+
+    # Act
+    instruments = await client.instrument("deribit", "BTC_USDC")
+
+    # Assert
+    assert len(instruments) == 1
+    assert isinstance(instruments[0], nautilus_pyo3.CurrencyPair)